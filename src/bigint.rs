@@ -0,0 +1,398 @@
+use crate::{matrix_traits::*, prelude::*, scalar::*};
+use core::{cmp::Ordering, fmt, ops, str};
+
+/*
+ * Arbitrary-precision signed integer, stored as sign + magnitude.
+ * The magnitude is little-endian base 2^32 with no leading zero limbs, so 0 is an empty vector.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    digits: Vec<u32>
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        // There is no -0
+        let negative = negative && !digits.is_empty();
+        BigInt { negative, digits }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    // -1, 0 or 1
+    pub fn signum(&self) -> i32 {
+        if self.digits.is_empty() { 0 } else if self.negative { -1 } else { 1 }
+    }
+
+    pub fn bits(&self) -> usize {
+        match self.digits.last() {
+            Some(top) => 32 * self.digits.len() - top.leading_zeros() as usize,
+            None => 0
+        }
+    }
+
+    pub fn pow(&self, exponent: u32) -> BigInt {
+        let mut ret = BigInt::from(1);
+        let mut base = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                ret = ret.mul(&base);
+            }
+            base = base.mul(&base);
+            exponent >>= 1;
+        }
+        ret
+    }
+
+    // Only the value of the result is checked, None if it doesn't fit
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.digits.len() > 2 {
+            return None;
+        }
+
+        let magnitude = self.digits.iter().rev().fold(0u64, |acc, &d| (acc << 32) | d as u64);
+        if self.negative {
+            0i64.checked_sub_unsigned(magnitude)
+        }
+        else {
+            i64::try_from(magnitude).ok()
+        }
+    }
+}
+
+///
+/// Magnitude arithmetic
+///
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut ret = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &digit) in long.iter().enumerate() {
+        let sum = digit as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        ret.push(sum as u32);
+        carry = sum >> 32;
+    }
+
+    if carry > 0 {
+        ret.push(carry as u32);
+    }
+    ret
+}
+
+// Requires a >= b
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut ret = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &digit) in a.iter().enumerate() {
+        let mut diff = digit as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if diff < 0 {
+            diff += 1 << 32;
+            borrow = 1;
+        }
+        ret.push(diff as u32);
+    }
+    ret
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let mut ret = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let current = ret[i + j] as u64 + x as u64 * y as u64 + carry;
+            ret[i + j] = current as u32;
+            carry = current >> 32;
+        }
+        ret[i + b.len()] = carry as u32;
+    }
+    ret
+}
+
+// Single limb divisor - used for printing and as a fast path
+fn div_rem_small(a: &[u32], b: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+    for i in (0..a.len()).rev() {
+        let current = (remainder << 32) | a[i] as u64;
+        quotient[i] = (current / b as u64) as u32;
+        remainder = current % b as u64;
+    }
+    (quotient, remainder as u32)
+}
+
+// Binary long division, one bit of the dividend at a time
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if b.is_empty() {
+        panic!("Division of a BigInt by 0");
+    }
+
+    if cmp_magnitude(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+
+    if b.len() == 1 {
+        let (quotient, remainder) = div_rem_small(a, b[0]);
+        return (quotient, vec![remainder]);
+    }
+
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder: Vec<u32> = vec![];
+    for bit in (0..32 * a.len()).rev() {
+
+        // remainder = remainder * 2 + next bit of a
+        let mut carry = (a[bit / 32] >> (bit % 32)) & 1;
+        for digit in remainder.iter_mut() {
+            let next_carry = *digit >> 31;
+            *digit = (*digit << 1) | carry;
+            carry = next_carry;
+        }
+        if carry > 0 {
+            remainder.push(carry);
+        }
+
+        if cmp_magnitude(&remainder, b) != Ordering::Less {
+            remainder = sub_magnitude(&remainder, b);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (quotient, remainder)
+}
+
+///
+/// Conversions
+///
+macro_rules! bigint_from_impl {
+    ($($type:ty),* $(,)*) => {$(
+        impl From<$type> for BigInt {
+            fn from(value: $type) -> Self {
+                let mut magnitude = value.unsigned_abs() as u128;
+                let mut digits = vec![];
+                while magnitude > 0 {
+                    digits.push(magnitude as u32);
+                    magnitude >>= 32;
+                }
+
+                BigInt::from_parts(value < 0, digits)
+            }
+        }
+    )*};
+}
+
+bigint_from_impl!(i8, i16, i32, i64, i128, isize);
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+impl str::FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s))
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid integer: \"{s}\""));
+        }
+
+        // Feed 9 decimal digits at a time - 10^9 still fits into a limb
+        let mut magnitude: Vec<u32> = vec![];
+        for chunk in digits.as_bytes().chunks(9) {
+            let value: u32 = str::from_utf8(chunk).unwrap().parse().unwrap();
+            magnitude = mul_magnitude(&magnitude, &[10u32.pow(chunk.len() as u32)]);
+            magnitude = add_magnitude(&magnitude, &[value]);
+        }
+
+        Ok(BigInt::from_parts(negative, magnitude))
+    }
+}
+
+///
+/// Element traits
+///
+impl MatrixElem for BigInt {
+    fn add(&self, rhs: &Self) -> Self {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_magnitude(&self.digits, &rhs.digits));
+        }
+
+        // Different signs - subtract the smaller magnitude from the bigger one
+        match cmp_magnitude(&self.digits, &rhs.digits) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, sub_magnitude(&rhs.digits, &self.digits)),
+            _ => BigInt::from_parts(self.negative, sub_magnitude(&self.digits, &rhs.digits))
+        }
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        BigInt::from_parts(self.negative != rhs.negative, mul_magnitude(&self.digits, &rhs.digits))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        let magnitude = self.digits.iter().rev().fold(0f64, |acc, &d| acc * 4294967296. + d as f64);
+        Some(if self.negative { -magnitude } else { magnitude })
+    }
+}
+
+impl MatrixSub for BigInt {
+    fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+}
+
+impl MatrixZeroOne for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+
+    fn is_one(&self) -> bool {
+        !self.negative && self.digits == [1]
+    }
+}
+
+impl Ring for BigInt {
+    fn neg(&self) -> Self {
+        BigInt::from_parts(!self.negative, self.digits.clone())
+    }
+}
+
+impl Integer for BigInt {
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quotient, remainder) = div_rem_magnitude(&self.digits, &rhs.digits);
+        (BigInt::from_parts(self.negative != rhs.negative, quotient), BigInt::from_parts(self.negative, remainder))
+    }
+
+    fn abs(&self) -> Self {
+        BigInt::from_parts(false, self.digits.clone())
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.digits, &other.digits),
+            (true, true) => cmp_magnitude(&other.digits, &self.digits)
+        }
+    }
+}
+
+///
+/// Operators
+///
+impl ops::Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MatrixElem::add(&self, &rhs)
+    }
+}
+
+impl ops::Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        MatrixSub::sub(&self, &rhs)
+    }
+}
+
+impl ops::Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        MatrixElem::mul(&self, &rhs)
+    }
+}
+
+// Rounds towards 0, like the primitive integers
+impl ops::Div for BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl ops::Rem for BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl ops::Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        Ring::neg(&self)
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+
+        // Split into base 10^9 chunks, least significant first
+        let mut chunks = vec![];
+        let mut magnitude = self.digits.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_small(&magnitude, 1_000_000_000);
+            chunks.push(remainder);
+            magnitude = quotient;
+            while magnitude.last() == Some(&0) {
+                magnitude.pop();
+            }
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", chunks[chunks.len() - 1])?;
+        for chunk in chunks.iter().rev().skip(1) {
+            write!(f, "{:09}", chunk)?;
+        }
+        Ok(())
+    }
+}
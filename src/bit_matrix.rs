@@ -0,0 +1,299 @@
+use crate::{finite_field::*, matrix::*, matrix_traits::*, prelude::*};
+use core::{fmt, ops};
+
+const WORD: usize = 64;
+
+/*
+ * Matrix over GF(2) with every row packed into 64 bit words - addition is xor and
+ * row operations work on whole words. Bits past the width are always kept at 0.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitMatrix {
+    rows: Vec<Vec<u64>>,
+    width: usize
+}
+
+impl<const M: usize, const N: usize> From<[[u8; M]; N]> for BitMatrix {
+    fn from(arr: [[u8; M]; N]) -> Self {
+        let mut m = BitMatrix::new(N, M);
+        for (row, values) in arr.iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                m.set(row, col, value % 2 == 1);
+            }
+        }
+        m
+    }
+}
+
+impl BitMatrix {
+    pub fn new(height: usize, width: usize) -> Self {
+        BitMatrix { rows: vec![vec![0; width.div_ceil(WORD)]; height], width }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut m = BitMatrix::new(size, size);
+        for i in 0..size {
+            m.set(i, i, true);
+        }
+        m
+    }
+
+    pub fn from_matrix(a: &Matrix<Mod<2>>) -> Self {
+        let mut m = BitMatrix::new(a.height(), a.width());
+        for row in 0..a.height() {
+            for col in 0..a.width() {
+                m.set(row, col, a.get(row, col).is_one());
+            }
+        }
+        m
+    }
+
+    pub fn to_matrix(&self) -> Matrix<Mod<2>> {
+        let mut m = Matrix::new_fill(self.height(), self.width(), Mod::ZERO);
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                if self.get(row, col) {
+                    m.set(row, col, Mod::ONE);
+                }
+            }
+        }
+        m
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, row_index: usize, col_index: usize) -> bool {
+        if row_index >= self.height() || col_index >= self.width() {
+            panic!("Index out of bounds! [{row_index}, {col_index}] / [{}, {}]", self.height(), self.width())
+        }
+
+        (self.rows[row_index][col_index / WORD] >> (col_index % WORD)) & 1 == 1
+    }
+
+    pub fn set(&mut self, row_index: usize, col_index: usize, value: bool) {
+        if row_index >= self.height() || col_index >= self.width() {
+            panic!("Index out of bounds! [{row_index}, {col_index}] / [{}, {}]", self.height(), self.width())
+        }
+
+        let mask = 1 << (col_index % WORD);
+        let word = &mut self.rows[row_index][col_index / WORD];
+        if value { *word |= mask } else { *word &= !mask }
+    }
+
+    pub fn swap_rows(&mut self, row_1: usize, row_2: usize) {
+        self.rows.swap(row_1, row_2);
+    }
+
+    // Number of 1s, the Hamming weight for a single row or column
+    pub fn count_ones(&self) -> usize {
+        self.rows.iter().flatten().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut ret = BitMatrix::new(self.width(), self.height());
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                if self.get(row, col) {
+                    ret.set(col, row, true);
+                }
+            }
+        }
+        ret
+    }
+
+    // row_target += row_source
+    fn xor_row(&mut self, target: usize, source: usize) {
+        let source = self.rows[source].clone();
+        for (word, other) in self.rows[target].iter_mut().zip(source) {
+            *word ^= other;
+        }
+    }
+
+    // [self | other]
+    fn augment(&self, other: &BitMatrix) -> BitMatrix {
+        let mut ret = BitMatrix::new(self.height(), self.width() + other.width());
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                ret.set(row, col, self.get(row, col));
+            }
+            for col in 0..other.width() {
+                ret.set(row, self.width() + col, other.get(row, col));
+            }
+        }
+        ret
+    }
+
+    fn cut_cols(&self, col_start: usize, col_end: usize) -> BitMatrix {
+        let mut ret = BitMatrix::new(self.height(), col_end - col_start);
+        for row in 0..self.height() {
+            for col in col_start..col_end {
+                ret.set(row, col - col_start, self.get(row, col));
+            }
+        }
+        ret
+    }
+
+    /*
+     * Gauss-Jordan elimination in place over the first cols columns, returns the pivot columns.
+     * Every non-zero is a pivot and every pivot is already 1, so it's only swaps and xors.
+     */
+    fn reduce(&mut self, cols: usize) -> Vec<usize> {
+        let mut pivots = vec![];
+        for col in 0..cols {
+            let row = pivots.len();
+            if row == self.height() {
+                break;
+            }
+
+            let Some(pivot_row) = (row..self.height()).find(|&r| self.get(r, col)) else {
+                continue;
+            };
+            self.swap_rows(row, pivot_row);
+
+            for row_index in 0..self.height() {
+                if row_index != row && self.get(row_index, col) {
+                    self.xor_row(row_index, row);
+                }
+            }
+            pivots.push(col);
+        }
+        pivots
+    }
+
+    pub fn rref(&self) -> BitMatrix {
+        let mut r = self.clone();
+        r.reduce(self.width());
+        r
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().reduce(self.width()).len()
+    }
+
+    pub fn inv(&self) -> BitMatrix {
+        if self.height() != self.width() {
+            panic!("Cannot inverse a non-square matrix! A=[{},{}]", self.height(), self.width());
+        }
+
+        // [A | I] -> [I | A^-1]
+        let mut augmented = self.augment(&BitMatrix::identity(self.height()));
+        if augmented.reduce(self.width()).len() < self.height() {
+            panic!("Cannot inverse a singular matrix!");
+        }
+        augmented.cut_cols(self.width(), 2 * self.width())
+    }
+
+    // Basis of {x : Ax = 0}, one vector per column
+    pub fn null_space(&self) -> BitMatrix {
+        let mut reduced = self.clone();
+        let pivots = reduced.reduce(self.width());
+        let free: Vec<usize> = (0..self.width()).filter(|col| !pivots.contains(col)).collect();
+
+        let mut ret = BitMatrix::new(self.width(), free.len());
+        for (index, &free_col) in free.iter().enumerate() {
+            ret.set(free_col, index, true);
+            for (row, &pivot_col) in pivots.iter().enumerate() {
+                ret.set(pivot_col, index, reduced.get(row, free_col));
+            }
+        }
+        ret
+    }
+
+    // One solution of Ax = b with free variables set to 0, None if there is none
+    pub fn solve(&self, b: &BitMatrix) -> Option<BitMatrix> {
+        if b.height() != self.height() {
+            panic!("b must have the same height as A, A=[{},{}], b=[{},{}]", self.height(), self.width(), b.height(), b.width());
+        }
+
+        // Reducing [A | b] fully - a pivot in the b part means a row 0 = 1
+        let mut augmented = self.augment(b);
+        let pivots = augmented.reduce(augmented.width());
+        if pivots.last().is_some_and(|&col| col >= self.width()) {
+            return None;
+        }
+
+        let mut ret = BitMatrix::new(self.width(), b.width());
+        for (row, &pivot_col) in pivots.iter().enumerate() {
+            for col in 0..b.width() {
+                ret.set(pivot_col, col, augmented.get(row, self.width() + col));
+            }
+        }
+        Some(ret)
+    }
+}
+
+///
+/// Operators
+///
+impl ops::Add for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.height() != rhs.height() {
+            panic!("Can't add matrices A+B: A.height ({}) != B.height ({})", self.height(), rhs.height())
+        }
+
+        if self.width() != rhs.width() {
+            panic!("Can't add matrices A+B: A.width ({}) != B.width ({})", self.width(), rhs.width())
+        }
+
+        let mut ret = self.clone();
+        for (row, other) in ret.rows.iter_mut().zip(&rhs.rows) {
+            for (word, other) in row.iter_mut().zip(other) {
+                *word ^= other;
+            }
+        }
+        ret
+    }
+}
+
+impl ops::Add for BitMatrix {
+    type Output = BitMatrix;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+// Row i of AB is the xor of the rows of B picked by the 1s in row i of A
+impl ops::Mul for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.width() != rhs.height() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.width(), rhs.height())
+        }
+
+        let mut ret = BitMatrix::new(self.height(), rhs.width());
+        for row in 0..self.height() {
+            for k in 0..self.width() {
+                if self.get(row, k) {
+                    for (word, other) in ret.rows[row].iter_mut().zip(&rhs.rows[k]) {
+                        *word ^= other;
+                    }
+                }
+            }
+        }
+        ret
+    }
+}
+
+impl ops::Mul for BitMatrix {
+    type Output = BitMatrix;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl fmt::Display for BitMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_matrix().fmt(f)
+    }
+}
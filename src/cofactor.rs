@@ -0,0 +1,90 @@
+use crate::{matrix::*, scalar::*};
+
+/*
+ * Cofactor (Laplace) expansion - no divisions and no pivoting, so it works over any ring
+ * and keeps symbolic entries readable. O(n!), only meant for small matrices.
+ */
+
+// A without row and col
+fn minor<T: Clone>(a: &Matrix<T>, row: usize, col: usize) -> Matrix<T> {
+    let size = a.height() - 1;
+    let mut ret = Matrix::new_fill(size, size, a.get(0, 0));
+    for r in 0..size {
+        for c in 0..size {
+            let source_row = if r < row { r } else { r + 1 };
+            let source_col = if c < col { c } else { c + 1 };
+            ret[(r, c)] = a.get(source_row, source_col);
+        }
+    }
+    ret
+}
+
+pub fn cofactor_det<T: Ring>(a: &Matrix<T>) -> T {
+    if a.height() != a.width() {
+        panic!("Cannot compute determinant of a non-square matrix! A=[{},{}]", a.height(), a.width());
+    }
+
+    match a.height() {
+        0 => T::one(),
+        1 => a.get(0, 0),
+        2 => a[(0, 0)].mul(&a[(1, 1)]).sub(&a[(0, 1)].mul(&a[(1, 0)])),
+        size => {
+
+            // Expand along the first row, skipping zeros
+            let mut ret = T::zero();
+            for col in 0..size {
+                let value = a.get(0, col);
+                if value.is_zero() {
+                    continue;
+                }
+
+                let term = value.mul(&cofactor_det(&minor(a, 0, col)));
+                ret = if col % 2 == 0 { ret.add(&term) } else { ret.sub(&term) };
+            }
+            ret
+        }
+    }
+}
+
+// Transposed matrix of cofactors, A * adj(A) = det(A) * I
+pub fn adjugate<T: Ring>(a: &Matrix<T>) -> Matrix<T> {
+    if a.height() != a.width() {
+        panic!("Cannot compute adjugate of a non-square matrix! A=[{},{}]", a.height(), a.width());
+    }
+
+    let size = a.height();
+    if size == 1 {
+        return Matrix::new_fill(1, 1, T::one());
+    }
+
+    let mut ret = Matrix::new_fill(size, size, T::zero());
+    for row in 0..size {
+        for col in 0..size {
+            let cofactor = cofactor_det(&minor(a, row, col));
+            ret[(col, row)] = if (row + col) % 2 == 0 { cofactor } else { cofactor.neg() };
+        }
+    }
+    ret
+}
+
+// A^-1 = adj(A) / det(A)
+pub fn cofactor_inv<T: Field>(a: &Matrix<T>) -> Matrix<T> {
+    if a.height() != a.width() {
+        panic!("Cannot inverse a non-square matrix! A=[{},{}]", a.height(), a.width());
+    }
+
+    let det = cofactor_det(a);
+    if det.is_zero() {
+        panic!("Cannot inverse a singular matrix!");
+    }
+
+    let det = det.recip();
+    let adjugate = adjugate(a);
+    let mut ret = adjugate.clone();
+    for row in 0..a.height() {
+        for col in 0..a.width() {
+            ret[(row, col)] = adjugate[(row, col)].mul(&det);
+        }
+    }
+    ret
+}
@@ -0,0 +1,188 @@
+use crate::{matrix_traits::*, scalar::*};
+use core::{fmt, ops};
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T
+}
+
+impl<T> Complex<T> {
+    pub const fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+}
+
+impl<T: RealField> Complex<T> {
+    pub fn i() -> Self {
+        Complex::new(T::zero(), T::one())
+    }
+
+    pub fn conj(&self) -> Self {
+        Complex::new(self.re.clone(), self.im.neg())
+    }
+
+    // |z|^2, cheaper than the modulus as there is no square root
+    pub fn norm_sqr(&self) -> T {
+        self.re.mul(&self.re).add(&self.im.mul(&self.im))
+    }
+
+    pub fn abs(&self) -> T {
+        self.norm_sqr().sqrt()
+    }
+}
+
+impl<T: RealField> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Complex::new(re, T::zero())
+    }
+}
+
+///
+/// Element traits
+///
+impl<T: RealField> MatrixElem for Complex<T> {
+    fn add(&self, rhs: &Self) -> Self {
+        Complex::new(self.re.add(&rhs.re), self.im.add(&rhs.im))
+    }
+
+    // (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+    fn mul(&self, rhs: &Self) -> Self {
+        Complex::new(
+            self.re.mul(&rhs.re).sub(&self.im.mul(&rhs.im)),
+            self.re.mul(&rhs.im).add(&self.im.mul(&rhs.re)))
+    }
+
+    // No real value of its own, but a modulus
+    fn modulus_f64(&self) -> Option<f64> {
+        self.abs().to_f64()
+    }
+}
+
+impl<T: RealField> MatrixSub for Complex<T> {
+    fn sub(&self, rhs: &Self) -> Self {
+        Complex::new(self.re.sub(&rhs.re), self.im.sub(&rhs.im))
+    }
+}
+
+impl<T: RealField> MatrixZeroOne for Complex<T> {
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.re.is_one() && self.im.is_zero()
+    }
+}
+
+impl<T: RealField + MatrixConstZeroOne> MatrixConstZeroOne for Complex<T> {
+    const ZERO: Self = Complex::new(T::ZERO, T::ZERO);
+    const ONE: Self = Complex::new(T::ONE, T::ZERO);
+}
+
+impl<T: RealField> Ring for Complex<T> {
+    fn neg(&self) -> Self {
+        Complex::new(self.re.neg(), self.im.neg())
+    }
+}
+
+impl<T: RealField<Magnitude = T>> Field for Complex<T> {
+    type Magnitude = T;
+
+    // z / w = z * conj(w) / |w|^2
+    fn div(&self, rhs: &Self) -> Self {
+        let numerator = self.mul(&rhs.conj());
+        let denominator = rhs.norm_sqr();
+        Complex::new(numerator.re.div(&denominator), numerator.im.div(&denominator))
+    }
+
+    fn magnitude(&self) -> T {
+        self.abs()
+    }
+
+    fn negligible(scale: &T) -> T {
+        T::negligible(scale)
+    }
+
+    fn flush_negligible(&self) -> Self {
+        Complex::new(self.re.flush_negligible(), self.im.flush_negligible())
+    }
+}
+
+impl<T: RealField<Magnitude = T>> ComplexField for Complex<T> {
+    type Real = T;
+
+    fn conj(&self) -> Self {
+        Complex::conj(self)
+    }
+
+    fn modulus(&self) -> T {
+        self.abs()
+    }
+
+    fn from_real(value: T) -> Self {
+        Complex::from(value)
+    }
+}
+
+///
+/// Operators
+///
+impl<T: RealField> ops::Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MatrixElem::add(&self, &rhs)
+    }
+}
+
+impl<T: RealField> ops::Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        MatrixSub::sub(&self, &rhs)
+    }
+}
+
+impl<T: RealField> ops::Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        MatrixElem::mul(&self, &rhs)
+    }
+}
+
+impl<T: RealField<Magnitude = T>> ops::Div for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Field::div(&self, &rhs)
+    }
+}
+
+impl<T: RealField> ops::Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Self::Output {
+        Ring::neg(&self)
+    }
+}
+
+// Prints as a+bi, precision is applied to both parts
+impl<T: RealField + fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.im < T::zero() { "-" } else { "+" };
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}{}{:.*}i", precision, self.re, sign, precision, self.im.abs()),
+            None => write!(f, "{}{}{}i", self.re, sign, self.im.abs())
+        }
+    }
+}
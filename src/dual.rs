@@ -0,0 +1,492 @@
+use crate::{matrix::*, matrix_traits::*, prelude::*, scalar::*};
+use core::{cmp::Ordering, fmt, ops};
+
+/*
+ * Dual number re + eps*e with e^2 = 0. Evaluating f(x + e) gives f(x) + f'(x)e,
+ * so pushing duals through any matrix computation differentiates it (forward mode).
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Dual<T> {
+    pub re: T,
+    pub eps: T
+}
+
+impl<T> Dual<T> {
+    pub const fn new(re: T, eps: T) -> Self {
+        Dual { re, eps }
+    }
+}
+
+impl<T: RealField> Dual<T> {
+
+    // Constant - derivative 0
+    pub fn constant(re: T) -> Self {
+        Dual::new(re, T::zero())
+    }
+
+    // Independent variable - derivative 1
+    pub fn variable(re: T) -> Self {
+        Dual::new(re, T::one())
+    }
+
+    // g(f(x)) with g(re) = value and g'(re) = derivative
+    pub fn chain(&self, value: T, derivative: T) -> Self {
+        Dual::new(value, derivative.mul(&self.eps))
+    }
+
+    pub fn powi(&self, exponent: i32) -> Self {
+        if exponent == 0 {
+            return Dual::one();
+        }
+
+        let base = if exponent < 0 { self.recip() } else { self.clone() };
+        let mut ret = base.clone();
+        for _ in 1..exponent.unsigned_abs() {
+            ret = ret.mul(&base);
+        }
+        ret
+    }
+}
+
+impl<T: RealField> From<T> for Dual<T> {
+    fn from(re: T) -> Self {
+        Dual::constant(re)
+    }
+}
+
+///
+/// Element traits
+///
+impl<T: RealField> MatrixElem for Dual<T> {
+    fn add(&self, rhs: &Self) -> Self {
+        Dual::new(self.re.add(&rhs.re), self.eps.add(&rhs.eps))
+    }
+
+    // (a + be)(c + de) = ac + (ad + bc)e
+    fn mul(&self, rhs: &Self) -> Self {
+        Dual::new(self.re.mul(&rhs.re), self.re.mul(&rhs.eps).add(&self.eps.mul(&rhs.re)))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.re.to_f64()
+    }
+}
+
+impl<T: RealField> MatrixSub for Dual<T> {
+    fn sub(&self, rhs: &Self) -> Self {
+        Dual::new(self.re.sub(&rhs.re), self.eps.sub(&rhs.eps))
+    }
+}
+
+impl<T: RealField> MatrixZeroOne for Dual<T> {
+    fn zero() -> Self {
+        Dual::constant(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.eps.is_zero()
+    }
+
+    fn one() -> Self {
+        Dual::constant(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.re.is_one() && self.eps.is_zero()
+    }
+}
+
+impl<T: RealField + MatrixConstZeroOne> MatrixConstZeroOne for Dual<T> {
+    const ZERO: Self = Dual::new(T::ZERO, T::ZERO);
+    const ONE: Self = Dual::new(T::ONE, T::ZERO);
+}
+
+impl<T: RealField> Ring for Dual<T> {
+    fn neg(&self) -> Self {
+        Dual::new(self.re.neg(), self.eps.neg())
+    }
+}
+
+impl<T: RealField> Field for Dual<T> {
+
+    // Pivoting only looks at the value, the derivative follows whatever path the value took
+    type Magnitude = T::Magnitude;
+
+    fn div(&self, rhs: &Self) -> Self {
+        self.mul(&rhs.recip())
+    }
+
+    fn magnitude(&self) -> T::Magnitude {
+        self.re.magnitude()
+    }
+
+    fn negligible(scale: &T::Magnitude) -> T::Magnitude {
+        T::negligible(scale)
+    }
+
+    // (1/x)' = -1/x^2
+    fn recip(&self) -> Self {
+        let value = self.re.recip();
+        self.chain(value.clone(), value.mul(&value).neg())
+    }
+}
+
+impl<T: RealField> RealField for Dual<T> {
+    fn abs(&self) -> Self {
+        if self.re < T::zero() { self.neg() } else { self.clone() }
+    }
+
+    // (sqrt x)' = 1 / (2 sqrt x)
+    fn sqrt(&self) -> Self {
+        let value = self.re.sqrt();
+        let derivative = value.add(&value).recip();
+        self.chain(value, derivative)
+    }
+
+    fn epsilon() -> Self {
+        Dual::constant(T::epsilon())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Dual::constant(T::from_f64(value))
+    }
+}
+
+impl<T: RealField> ComplexField for Dual<T> {
+    type Real = Dual<T>;
+
+    fn conj(&self) -> Self {
+        self.clone()
+    }
+
+    fn modulus(&self) -> Self {
+        RealField::abs(self)
+    }
+
+    fn from_real(value: Self) -> Self {
+        value
+    }
+}
+
+// Ordered by value first, derivative only breaks ties so that it agrees with ==
+impl<T: RealField> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.re.partial_cmp(&other.re)? {
+            Ordering::Equal => self.eps.partial_cmp(&other.eps),
+            ordering => Some(ordering)
+        }
+    }
+}
+
+/*
+ * Hyper-dual number re + e1*eps1 + e2*eps2 + e12*eps1eps2 with eps1^2 = eps2^2 = 0.
+ * Seeding eps1 and eps2 on two variables makes the e12 part the mixed second derivative.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct HyperDual<T> {
+    pub re: T,
+    pub e1: T,
+    pub e2: T,
+    pub e12: T
+}
+
+impl<T> HyperDual<T> {
+    pub const fn new(re: T, e1: T, e2: T, e12: T) -> Self {
+        HyperDual { re, e1, e2, e12 }
+    }
+}
+
+impl<T: RealField> HyperDual<T> {
+    pub fn constant(re: T) -> Self {
+        HyperDual::new(re, T::zero(), T::zero(), T::zero())
+    }
+
+    // g(f(x)) with g(re), g'(re) and g''(re) given
+    pub fn chain(&self, value: T, derivative: T, second_derivative: T) -> Self {
+        HyperDual::new(
+            value,
+            derivative.mul(&self.e1),
+            derivative.mul(&self.e2),
+            derivative.mul(&self.e12).add(&second_derivative.mul(&self.e1).mul(&self.e2)))
+    }
+}
+
+impl<T: RealField> From<T> for HyperDual<T> {
+    fn from(re: T) -> Self {
+        HyperDual::constant(re)
+    }
+}
+
+impl<T: RealField> MatrixElem for HyperDual<T> {
+    fn add(&self, rhs: &Self) -> Self {
+        HyperDual::new(self.re.add(&rhs.re), self.e1.add(&rhs.e1), self.e2.add(&rhs.e2), self.e12.add(&rhs.e12))
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        HyperDual::new(
+            self.re.mul(&rhs.re),
+            self.re.mul(&rhs.e1).add(&self.e1.mul(&rhs.re)),
+            self.re.mul(&rhs.e2).add(&self.e2.mul(&rhs.re)),
+            self.re.mul(&rhs.e12).add(&self.e1.mul(&rhs.e2)).add(&self.e2.mul(&rhs.e1)).add(&self.e12.mul(&rhs.re)))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.re.to_f64()
+    }
+}
+
+impl<T: RealField> MatrixSub for HyperDual<T> {
+    fn sub(&self, rhs: &Self) -> Self {
+        HyperDual::new(self.re.sub(&rhs.re), self.e1.sub(&rhs.e1), self.e2.sub(&rhs.e2), self.e12.sub(&rhs.e12))
+    }
+}
+
+impl<T: RealField> MatrixZeroOne for HyperDual<T> {
+    fn zero() -> Self {
+        HyperDual::constant(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.e1.is_zero() && self.e2.is_zero() && self.e12.is_zero()
+    }
+
+    fn one() -> Self {
+        HyperDual::constant(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.re.is_one() && self.e1.is_zero() && self.e2.is_zero() && self.e12.is_zero()
+    }
+}
+
+impl<T: RealField + MatrixConstZeroOne> MatrixConstZeroOne for HyperDual<T> {
+    const ZERO: Self = HyperDual::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+    const ONE: Self = HyperDual::new(T::ONE, T::ZERO, T::ZERO, T::ZERO);
+}
+
+impl<T: RealField> Ring for HyperDual<T> {
+    fn neg(&self) -> Self {
+        HyperDual::new(self.re.neg(), self.e1.neg(), self.e2.neg(), self.e12.neg())
+    }
+}
+
+impl<T: RealField> Field for HyperDual<T> {
+    type Magnitude = T::Magnitude;
+
+    fn div(&self, rhs: &Self) -> Self {
+        self.mul(&rhs.recip())
+    }
+
+    fn magnitude(&self) -> T::Magnitude {
+        self.re.magnitude()
+    }
+
+    fn negligible(scale: &T::Magnitude) -> T::Magnitude {
+        T::negligible(scale)
+    }
+
+    // (1/x)' = -1/x^2, (1/x)'' = 2/x^3
+    fn recip(&self) -> Self {
+        let value = self.re.recip();
+        let square = value.mul(&value);
+        let cube = square.mul(&value);
+        self.chain(value, square.neg(), cube.add(&cube))
+    }
+}
+
+impl<T: RealField> RealField for HyperDual<T> {
+    fn abs(&self) -> Self {
+        if self.re < T::zero() { self.neg() } else { self.clone() }
+    }
+
+    // (sqrt x)' = 1 / (2 sqrt x), (sqrt x)'' = -1 / (4 x sqrt x)
+    fn sqrt(&self) -> Self {
+        let value = self.re.sqrt();
+        let derivative = value.add(&value).recip();
+        let second_derivative = derivative.div(&self.re.add(&self.re)).neg();
+        self.chain(value, derivative, second_derivative)
+    }
+
+    fn epsilon() -> Self {
+        HyperDual::constant(T::epsilon())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        HyperDual::constant(T::from_f64(value))
+    }
+}
+
+impl<T: RealField> ComplexField for HyperDual<T> {
+    type Real = HyperDual<T>;
+
+    fn conj(&self) -> Self {
+        self.clone()
+    }
+
+    fn modulus(&self) -> Self {
+        RealField::abs(self)
+    }
+
+    fn from_real(value: Self) -> Self {
+        value
+    }
+}
+
+impl<T: RealField> PartialOrd for HyperDual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        [(&self.re, &other.re), (&self.e1, &other.e1), (&self.e2, &other.e2), (&self.e12, &other.e12)]
+            .into_iter()
+            .map(|(a, b)| a.partial_cmp(b))
+            .find(|ordering| *ordering != Some(Ordering::Equal))
+            .unwrap_or(Some(Ordering::Equal))
+    }
+}
+
+///
+/// Derivatives
+///
+// Entries of a matrix in row-major order, how inputs and outputs are numbered below
+fn flatten<T: Clone>(a: &Matrix<T>) -> Vec<T> {
+    (0..a.height()).flat_map(|row| (0..a.width()).map(move |col| a.get(row, col))).collect()
+}
+
+/*
+ * Jacobian of f at x - entry [i, j] is d output_i / d input_j, with the entries of both
+ * matrices numbered in row-major order. One evaluation of f per entry of x.
+ */
+pub fn jacobian<T, F>(f: F, x: &Matrix<T>) -> Matrix<T>
+where
+    T: RealField,
+    F: Fn(&Matrix<Dual<T>>) -> Matrix<Dual<T>>
+{
+    let inputs = x.height() * x.width();
+    let mut columns: Vec<Vec<T>> = Vec::with_capacity(inputs);
+
+    for index in 0..inputs {
+        let mut seeded = Matrix::new_fill(x.height(), x.width(), Dual::zero());
+        for row in 0..x.height() {
+            for col in 0..x.width() {
+                let eps = if row * x.width() + col == index { T::one() } else { T::zero() };
+                seeded.set(row, col, Dual::new(x.get(row, col), eps));
+            }
+        }
+
+        columns.push(flatten(&f(&seeded)).into_iter().map(|value| value.eps).collect());
+    }
+
+    let outputs = columns.first().map_or(0, |column| column.len());
+    let mut ret = Matrix::new_fill(outputs, inputs, T::zero());
+    for (col, column) in columns.into_iter().enumerate() {
+        for (row, value) in column.into_iter().enumerate() {
+            ret.set(row, col, value);
+        }
+    }
+    ret
+}
+
+// Gradient of a scalar function as a matrix of the same shape as x
+pub fn gradient<T, F>(f: F, x: &Matrix<T>) -> Matrix<T>
+where
+    T: RealField,
+    F: Fn(&Matrix<Dual<T>>) -> Dual<T>
+{
+    let jacobian = jacobian(|a| Matrix::new_fill(1, 1, f(a)), x);
+    let mut ret = Matrix::new_fill(x.height(), x.width(), T::zero());
+    for row in 0..x.height() {
+        for col in 0..x.width() {
+            ret.set(row, col, jacobian.get(0, row * x.width() + col));
+        }
+    }
+    ret
+}
+
+/*
+ * Hessian of a scalar function at x, entries numbered in row-major order.
+ * Exact second derivatives with no step size - one evaluation of f per pair of entries.
+ */
+pub fn hessian<T, F>(f: F, x: &Matrix<T>) -> Matrix<T>
+where
+    T: RealField,
+    F: Fn(&Matrix<HyperDual<T>>) -> HyperDual<T>
+{
+    let inputs = flatten(x);
+    let size = inputs.len();
+    let mut ret = Matrix::new_fill(size, size, T::zero());
+
+    for i in 0..size {
+        for j in i..size {
+            let mut seeded = Matrix::new_fill(x.height(), x.width(), HyperDual::zero());
+            for (index, value) in inputs.iter().enumerate() {
+                let e1 = if index == i { T::one() } else { T::zero() };
+                let e2 = if index == j { T::one() } else { T::zero() };
+                seeded.set(index / x.width(), index % x.width(), HyperDual::new(value.clone(), e1, e2, T::zero()));
+            }
+
+            // Mixed partials are equal, only the upper triangle is evaluated
+            let value = f(&seeded).e12;
+            ret.set(j, i, value.clone());
+            ret.set(i, j, value);
+        }
+    }
+    ret
+}
+
+///
+/// Operators
+///
+macro_rules! dual_ops_impl {
+    ($($name:ident),* $(,)*) => {$(
+        impl<T: RealField> ops::Add for $name<T> {
+            type Output = $name<T>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                MatrixElem::add(&self, &rhs)
+            }
+        }
+
+        impl<T: RealField> ops::Sub for $name<T> {
+            type Output = $name<T>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                MatrixSub::sub(&self, &rhs)
+            }
+        }
+
+        impl<T: RealField> ops::Mul for $name<T> {
+            type Output = $name<T>;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                MatrixElem::mul(&self, &rhs)
+            }
+        }
+
+        impl<T: RealField> ops::Div for $name<T> {
+            type Output = $name<T>;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                Field::div(&self, &rhs)
+            }
+        }
+
+        impl<T: RealField> ops::Neg for $name<T> {
+            type Output = $name<T>;
+
+            fn neg(self) -> Self::Output {
+                Ring::neg(&self)
+            }
+        }
+    )*};
+}
+
+dual_ops_impl!(Dual, HyperDual);
+
+// Prints as re+epse, precision is applied to both parts
+impl<T: RealField + fmt::Display> fmt::Display for Dual<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.eps < T::zero() { "-" } else { "+" };
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}{}{:.*}e", precision, self.re, sign, precision, self.eps.abs()),
+            None => write!(f, "{}{}{}e", self.re, sign, self.eps.abs())
+        }
+    }
+}
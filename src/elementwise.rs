@@ -0,0 +1,78 @@
+use crate::{matrix::*, matrix_traits::*, scalar::*};
+
+/*
+ * Elementwise operations on two matrices. The +, - and / operators broadcast the same way as the
+ * broadcast_* methods, * is the matrix product so broadcast_mul() and hadamard() are its elementwise forms.
+ */
+impl<T: MatrixElem> Matrix<T> {
+
+    // Elementwise product, both matrices must have the same size
+    pub fn hadamard(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.zip_map(other, T::mul)
+    }
+
+    pub fn broadcast_add(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.broadcast_map(other, T::add)
+    }
+
+    // Elementwise, like hadamard() - * on two matrices is the matrix product
+    pub fn broadcast_mul(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.broadcast_map(other, T::mul)
+    }
+}
+
+impl<T: MatrixSub> Matrix<T> {
+    pub fn broadcast_sub(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.broadcast_map(other, T::sub)
+    }
+}
+
+impl<T: Field> Matrix<T> {
+
+    // Elementwise quotient, both matrices must have the same size
+    pub fn elementwise_div(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.zip_map(other, T::div)
+    }
+
+    pub fn broadcast_div(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.broadcast_map(other, T::div)
+    }
+}
+
+impl<T> Matrix<T> {
+
+    /*
+     * NumPy-style broadcasting - in each dimension the sizes have to be equal or one of them 1,
+     * which is then repeated. A matrix with a row vector applies it to every row, with a column
+     * vector to every column, and a column with a row gives their outer combination.
+     */
+    pub fn broadcast_map<U, V>(&self, other: &Matrix<U>, mut f: impl FnMut(&T, &U) -> V) -> Matrix<V> {
+        let height = broadcast_size(self.height(), other.height());
+        let width = broadcast_size(self.width(), other.width());
+        let (Some(height), Some(width)) = (height, width) else {
+            panic!("Can't broadcast matrices: A=[{},{}], B=[{},{}]", self.height(), self.width(), other.height(), other.width())
+        };
+
+        // A dimension of size 1 always reads index 0
+        let pick = |index: usize, size: usize| if size == 1 { 0 } else { index };
+
+        let mut ret = Matrix::with_capacity(height, width);
+        for row in 0..height {
+            for col in 0..width {
+                let a = &self[(pick(row, self.height()), pick(col, self.width()))];
+                let b = &other[(pick(row, other.height()), pick(col, other.width()))];
+                ret.push_to_row(row, f(a, b));
+            }
+        }
+        ret
+    }
+}
+
+fn broadcast_size(a: usize, b: usize) -> Option<usize> {
+    match (a, b) {
+        _ if a == b => Some(a),
+        (1, _) => Some(b),
+        (_, 1) => Some(a),
+        _ => None
+    }
+}
@@ -0,0 +1,574 @@
+use crate::{float::Float, matrix::*, matrix_traits::*, prelude::*, scalar::*};
+use core::{cmp::Ordering, fmt, ops};
+
+/*
+ * Symbolic expression tree. Sums and products are n-ary and kept in a canonical
+ * form by simplify(): constants folded, like terms and like factors collected,
+ * operands sorted. The matrix arithmetic simplifies after every operation.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Const(f64),
+    Var(String),
+    Add(Vec<Expr>),
+    Mul(Vec<Expr>),
+    Pow(Box<Expr>, i32)
+}
+
+impl Expr {
+    pub fn var(name: &str) -> Expr {
+        Expr::Var(name.to_string())
+    }
+
+    pub fn constant(value: f64) -> Expr {
+        Expr::Const(value)
+    }
+
+    pub fn powi(&self, exponent: i32) -> Expr {
+        simplify_pow(self.simplify(), exponent)
+    }
+
+    pub fn as_const(&self) -> Option<f64> {
+        match self {
+            Expr::Const(value) => Some(*value),
+            _ => None
+        }
+    }
+
+    // Names of all variables, sorted and without duplicates
+    pub fn variables(&self) -> Vec<String> {
+        let mut ret = vec![];
+        self.collect_variables(&mut ret);
+        ret.sort();
+        ret.dedup();
+        ret
+    }
+
+    fn collect_variables(&self, ret: &mut Vec<String>) {
+        match self {
+            Expr::Const(_) => {},
+            Expr::Var(name) => ret.push(name.clone()),
+            Expr::Add(operands) | Expr::Mul(operands) => operands.iter().for_each(|e| e.collect_variables(ret)),
+            Expr::Pow(base, _) => base.collect_variables(ret)
+        }
+    }
+
+    pub fn simplify(&self) -> Expr {
+        match self {
+            Expr::Const(_) | Expr::Var(_) => self.clone(),
+            Expr::Add(terms) => simplify_sum(terms.iter().map(Expr::simplify)),
+            Expr::Mul(factors) => simplify_product(factors.iter().map(Expr::simplify)),
+            Expr::Pow(base, exponent) => simplify_pow(base.simplify(), *exponent)
+        }
+    }
+
+    // Multiplies out products of sums and positive powers of sums, then collects like terms
+    pub fn expand(&self) -> Expr {
+        match self.simplify() {
+            Expr::Add(terms) => simplify_sum(terms.iter().map(Expr::expand)),
+            Expr::Mul(factors) => expand_product(factors.iter().map(Expr::expand)),
+            Expr::Pow(base, exponent) if exponent > 1 && matches!(*base, Expr::Add(_)) => {
+                expand_product(core::iter::repeat_n(base.expand(), exponent as usize))
+            },
+            other => other
+        }
+    }
+
+    // Derivative with respect to the variable, simplified
+    pub fn diff(&self, name: &str) -> Expr {
+        match self {
+            Expr::Const(_) => Expr::Const(0.),
+            Expr::Var(var) => Expr::Const(if var == name { 1. } else { 0. }),
+            Expr::Add(terms) => simplify_sum(terms.iter().map(|e| e.diff(name))),
+
+            // Product rule - each factor differentiated in turn, times all the others
+            Expr::Mul(factors) => simplify_sum((0..factors.len()).map(|i| {
+                let others = factors.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, e)| e.clone());
+                simplify_product(core::iter::once(factors[i].diff(name)).chain(others))
+            })),
+            Expr::Pow(base, exponent) => simplify_product([
+                Expr::Const(*exponent as f64),
+                simplify_pow(base.simplify(), exponent - 1),
+                base.diff(name)
+            ].into_iter())
+        }
+    }
+
+    // Column of derivatives with respect to each of the variables
+    pub fn gradient(&self, vars: &[&str]) -> Matrix<Expr> {
+        let mut ret = Matrix::new_fill(vars.len(), 1, Expr::Const(0.));
+        for (row, name) in vars.iter().enumerate() {
+            ret.set(row, 0, self.diff(name));
+        }
+        ret
+    }
+
+    // Entry [i, j] is d^2 / d var_i d var_j
+    pub fn hessian(&self, vars: &[&str]) -> Matrix<Expr> {
+        let size = vars.len();
+        let mut ret = Matrix::new_fill(size, size, Expr::Const(0.));
+        for i in 0..size {
+            let first = self.diff(vars[i]);
+            for (j, name) in vars.iter().enumerate().skip(i) {
+
+                // Mixed partials are equal, only the upper triangle is differentiated
+                let value = first.diff(name);
+                ret.set(j, i, value.clone());
+                ret.set(i, j, value);
+            }
+        }
+        ret
+    }
+
+    // Replaces every occurrence of the variable and simplifies
+    pub fn subs(&self, name: &str, value: &Expr) -> Expr {
+        self.replace(name, value).simplify()
+    }
+
+    fn replace(&self, name: &str, value: &Expr) -> Expr {
+        match self {
+            Expr::Var(var) if var == name => value.clone(),
+            Expr::Const(_) | Expr::Var(_) => self.clone(),
+            Expr::Add(terms) => Expr::Add(terms.iter().map(|e| e.replace(name, value)).collect()),
+            Expr::Mul(factors) => Expr::Mul(factors.iter().map(|e| e.replace(name, value)).collect()),
+            Expr::Pow(base, exponent) => Expr::Pow(Box::new(base.replace(name, value)), *exponent)
+        }
+    }
+
+    pub fn eval(&self, values: &[(&str, f64)]) -> f64 {
+        match self {
+            Expr::Const(value) => *value,
+            Expr::Var(name) => match values.iter().find(|(var, _)| var == name) {
+                Some((_, value)) => *value,
+                None => panic!("Variable {name} has no value")
+            },
+            Expr::Add(terms) => terms.iter().map(|e| e.eval(values)).sum(),
+            Expr::Mul(factors) => factors.iter().map(|e| e.eval(values)).product(),
+            Expr::Pow(base, exponent) => Float::powi(base.eval(values), *exponent)
+        }
+    }
+
+    // How tightly the expression binds when printed - lower needs parentheses inside higher
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Add(_) => 1,
+            Expr::Const(value) if *value < 0. => 1,
+            Expr::Mul(_) => 2,
+            Expr::Pow(_, exponent) if *exponent < 0 => 2,
+            Expr::Pow(_, _) => 3,
+            Expr::Const(_) | Expr::Var(_) => 4
+        }
+    }
+}
+
+impl From<f64> for Expr {
+    fn from(value: f64) -> Self {
+        Expr::Const(value)
+    }
+}
+
+impl From<&str> for Expr {
+    fn from(name: &str) -> Self {
+        Expr::var(name)
+    }
+}
+
+///
+/// Simplification
+///
+// Canonical order of operands - by how they print, so equal expressions end up next to each other
+fn order(a: &Expr, b: &Expr) -> Ordering {
+    a.to_string().cmp(&b.to_string())
+}
+
+// Splits a term into its numeric coefficient and the rest, 2*x*y -> (2, x*y)
+fn split_coefficient(term: Expr) -> (f64, Option<Expr>) {
+    match term {
+        Expr::Const(value) => (value, None),
+        Expr::Mul(mut factors) => match factors.first() {
+            Some(Expr::Const(value)) => {
+                let value = *value;
+                factors.remove(0);
+                let rest = if factors.len() == 1 { factors.remove(0) } else { Expr::Mul(factors) };
+                (value, Some(rest))
+            },
+            _ => (1., Some(Expr::Mul(factors)))
+        },
+        other => (1., Some(other))
+    }
+}
+
+// Multiplies every term of each factor with every term of the others
+fn expand_product(factors: impl Iterator<Item = Expr>) -> Expr {
+    let mut terms = vec![Expr::Const(1.)];
+    for factor in factors {
+        let factor_terms = match factor {
+            Expr::Add(inner) => inner,
+            other => vec![other]
+        };
+        terms = terms.iter()
+            .flat_map(|term| factor_terms.iter().map(move |f| simplify_product([term.clone(), f.clone()].into_iter())))
+            .collect();
+    }
+    simplify_sum(terms.into_iter())
+}
+
+fn simplify_sum(terms: impl Iterator<Item = Expr>) -> Expr {
+    let mut constant = 0.;
+    let mut collected: Vec<(Expr, f64)> = vec![];
+
+    let mut stack: Vec<Expr> = terms.collect();
+    stack.reverse();
+    while let Some(term) = stack.pop() {
+        if let Expr::Add(inner) = term {
+            stack.extend(inner.into_iter().rev());
+            continue;
+        }
+
+        match split_coefficient(term) {
+            (value, None) => constant += value,
+            (value, Some(rest)) => match collected.iter_mut().find(|(e, _)| *e == rest) {
+                Some((_, coefficient)) => *coefficient += value,
+                None => collected.push((rest, value))
+            }
+        }
+    }
+
+    let mut ret: Vec<Expr> = collected.into_iter()
+        .filter(|(_, coefficient)| *coefficient != 0.)
+        .map(|(rest, coefficient)| {
+            if coefficient == 1. { rest } else { simplify_product([Expr::Const(coefficient), rest].into_iter()) }
+        })
+        .collect();
+
+    // Sorted without the coefficient, a term that is only a number goes first
+    let rest = |term: &Expr| split_coefficient(term.clone()).1;
+    ret.sort_by(|a, b| match (rest(a), rest(b)) {
+        (Some(a), Some(b)) => order(&a, &b),
+        (a, b) => a.is_some().cmp(&b.is_some())
+    });
+
+    if constant != 0. {
+        ret.push(Expr::Const(constant));
+    }
+
+    match ret.len() {
+        0 => Expr::Const(0.),
+        1 => ret.remove(0),
+        _ => Expr::Add(ret)
+    }
+}
+
+fn simplify_product(factors: impl Iterator<Item = Expr>) -> Expr {
+    let mut coefficient = 1.;
+    let mut powers: Vec<(Expr, i32)> = vec![];
+
+    let mut stack: Vec<Expr> = factors.collect();
+    stack.reverse();
+    while let Some(factor) = stack.pop() {
+        let (base, exponent) = match factor {
+            Expr::Const(value) => {
+                coefficient *= value;
+                continue;
+            },
+            Expr::Mul(inner) => {
+                stack.extend(inner.into_iter().rev());
+                continue;
+            },
+            Expr::Pow(base, exponent) => (*base, exponent),
+            other => (other, 1)
+        };
+
+        match powers.iter_mut().find(|(e, _)| *e == base) {
+            Some((_, total)) => *total += exponent,
+            None => powers.push((base, exponent))
+        }
+    }
+
+    if coefficient == 0. {
+        return Expr::Const(0.);
+    }
+
+    // A number times a single sum is distributed, so that -(a+b) becomes -a-b and its terms can be collected
+    if coefficient != 1. && powers.len() == 1 && powers[0].1 == 1 {
+        if let Expr::Add(terms) = &powers[0].0 {
+            return simplify_sum(terms.iter().map(|term| simplify_product([Expr::Const(coefficient), term.clone()].into_iter())));
+        }
+    }
+
+    let mut ret: Vec<Expr> = powers.into_iter()
+        .filter(|(_, exponent)| *exponent != 0)
+        .map(|(base, exponent)| if exponent == 1 { base } else { Expr::Pow(Box::new(base), exponent) })
+        .collect();
+    ret.sort_by(order);
+
+    if coefficient != 1. || ret.is_empty() {
+        ret.insert(0, Expr::Const(coefficient));
+    }
+
+    if ret.len() == 1 { ret.remove(0) } else { Expr::Mul(ret) }
+}
+
+fn simplify_pow(base: Expr, exponent: i32) -> Expr {
+    match (base, exponent) {
+        (_, 0) => Expr::Const(1.),
+        (base, 1) => base,
+        (Expr::Const(value), exponent) => {
+            if value == 0. && exponent < 0 {
+                panic!("Division of an expression by 0");
+            }
+            Expr::Const(Float::powi(value, exponent))
+        },
+        (Expr::Pow(base, inner), exponent) => simplify_pow(*base, inner * exponent),
+
+        // Integer powers distribute over products
+        (Expr::Mul(factors), exponent) => simplify_product(factors.into_iter().map(|f| simplify_pow(f, exponent))),
+        (base, exponent) => Expr::Pow(Box::new(base), exponent)
+    }
+}
+
+///
+/// Element traits
+///
+impl MatrixElem for Expr {
+
+    // Operands can be built from the variants directly, so they're simplified first
+    fn add(&self, rhs: &Self) -> Self {
+        simplify_sum([self.simplify(), rhs.simplify()].into_iter())
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        simplify_product([self.simplify(), rhs.simplify()].into_iter())
+    }
+
+    // Only expressions that are already a number
+    fn to_f64(&self) -> Option<f64> {
+        self.as_const()
+    }
+}
+
+impl MatrixSub for Expr {
+    fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+}
+
+impl MatrixZeroOne for Expr {
+    fn zero() -> Self {
+        Expr::Const(0.)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Expr::Const(0.)
+    }
+
+    fn one() -> Self {
+        Expr::Const(1.)
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Expr::Const(1.)
+    }
+}
+
+impl Ring for Expr {
+    fn neg(&self) -> Self {
+        simplify_product([Expr::Const(-1.), self.clone()].into_iter())
+    }
+}
+
+impl Field for Expr {
+
+    // Expressions can't be compared - anything that doesn't simplify to 0 is a valid pivot
+    type Magnitude = bool;
+
+    fn div(&self, rhs: &Self) -> Self {
+        self.mul(&rhs.recip())
+    }
+
+    fn magnitude(&self) -> bool {
+        !self.simplify().is_zero()
+    }
+
+    fn negligible(_: &bool) -> bool {
+        false
+    }
+
+    fn recip(&self) -> Self {
+        simplify_pow(self.clone(), -1)
+    }
+}
+
+impl Matrix<Expr> {
+    pub fn simplify(&self) -> Matrix<Expr> {
+        self.map(Expr::simplify)
+    }
+
+    pub fn subs(&self, name: &str, value: &Expr) -> Matrix<Expr> {
+        self.map(|e| e.subs(name, value))
+    }
+
+    pub fn eval(&self, values: &[(&str, f64)]) -> Matrix<f64> {
+        self.map(|e| e.eval(values))
+    }
+
+    pub fn diff(&self, name: &str) -> Matrix<Expr> {
+        self.map(|e| e.diff(name))
+    }
+
+    /*
+     * Jacobian of the entries taken as a vector in row-major order - entry [i, j] is
+     * d entry_i / d var_j. Evaluate it with eval() to get the numbers at a point.
+     */
+    pub fn jacobian(&self, vars: &[&str]) -> Matrix<Expr> {
+        let outputs = self.height() * self.width();
+        let mut ret = Matrix::new_fill(outputs, vars.len(), Expr::Const(0.));
+        for index in 0..outputs {
+            let entry = self.get(index / self.width(), index % self.width());
+            for (col, name) in vars.iter().enumerate() {
+                ret.set(index, col, entry.diff(name));
+            }
+        }
+        ret
+    }
+}
+
+///
+/// Operators
+///
+impl ops::Add for Expr {
+    type Output = Expr;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MatrixElem::add(&self, &rhs)
+    }
+}
+
+impl ops::Sub for Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        MatrixSub::sub(&self, &rhs)
+    }
+}
+
+impl ops::Mul for Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        MatrixElem::mul(&self, &rhs)
+    }
+}
+
+impl ops::Div for Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Field::div(&self, &rhs)
+    }
+}
+
+impl ops::Neg for Expr {
+    type Output = Expr;
+
+    fn neg(self) -> Self::Output {
+        Ring::neg(&self)
+    }
+}
+
+///
+/// Display
+///
+fn write_operand(f: &mut fmt::Formatter<'_>, e: &Expr, min_precedence: u8) -> fmt::Result {
+    if e.precedence() < min_precedence {
+        write!(f, "({})", e)
+    }
+    else {
+        write!(f, "{}", e)
+    }
+}
+
+// Factors with negative exponents go under a fraction bar: a*b^-1*c^-2 -> a/(b*c^2)
+fn write_product(f: &mut fmt::Formatter<'_>, factors: &[Expr]) -> fmt::Result {
+    let mut factors = factors;
+    if let Some(Expr::Const(value)) = factors.first() {
+        if *value == -1. && factors.len() > 1 {
+            write!(f, "-")?;
+            factors = &factors[1..];
+        }
+    }
+
+    let mut numerator: Vec<Expr> = vec![];
+    let mut denominator: Vec<Expr> = vec![];
+    for factor in factors {
+        match factor {
+            Expr::Pow(base, exponent) if *exponent < 0 => denominator.push(simplify_pow((**base).clone(), -exponent)),
+            other => numerator.push(other.clone())
+        }
+    }
+
+    if numerator.is_empty() {
+        write!(f, "1")?;
+    }
+    for (index, factor) in numerator.iter().enumerate() {
+        if index > 0 {
+            write!(f, "*")?;
+        }
+
+        // A leading constant doesn't need parentheses even when negative
+        match factor {
+            Expr::Const(_) if index == 0 => write!(f, "{}", factor)?,
+            _ => write_operand(f, factor, 2)?
+        }
+    }
+
+    if !denominator.is_empty() {
+        write!(f, "/")?;
+        if denominator.len() == 1 {
+            write_operand(f, &denominator[0], 3)?;
+        }
+        else {
+            write!(f, "({})", Expr::Mul(denominator))?;
+        }
+    }
+    Ok(())
+}
+
+// Constants print without a trailing .0 when they are whole numbers
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Const(value) => {
+                if Float::fract(*value) == 0. && value.abs() < 1e15 {
+                    write!(f, "{}", *value as i64)
+                }
+                else {
+                    write!(f, "{}", value)
+                }
+            },
+            Expr::Var(name) => write!(f, "{}", name),
+            Expr::Add(terms) => {
+                for (index, term) in terms.iter().enumerate() {
+                    let (coefficient, _) = split_coefficient(term.clone());
+                    if index > 0 && coefficient < 0. {
+                        write!(f, "-")?;
+                        write_operand(f, &Ring::neg(term), 2)?;
+                    }
+                    else {
+                        if index > 0 {
+                            write!(f, "+")?;
+                        }
+                        write_operand(f, term, if index == 0 { 1 } else { 2 })?;
+                    }
+                }
+                Ok(())
+            },
+            Expr::Mul(factors) => write_product(f, factors),
+            Expr::Pow(_, exponent) if *exponent < 0 => write_product(f, core::slice::from_ref(self)),
+            Expr::Pow(base, exponent) => {
+                write_operand(f, base, 4)?;
+                write!(f, "^{}", exponent)
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{matrix_traits::*, scalar::*};
+use core::{fmt, ops};
+
+/*
+ * Integers modulo a prime P, stored as the representative in [0, P).
+ * Arithmetic is exact so elimination only ever skips true zeros.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mod<const P: u64>(u64);
+
+const fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exponent: u64, p: u64) -> u64 {
+    let mut ret = 1 % p;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            ret = mul_mod(ret, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exponent >>= 1;
+    }
+    ret
+}
+
+// Miller-Rabin, these bases make it exact for every 64 bit number - only run at compile time
+const fn is_prime(p: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if p < 2 {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < BASES.len() {
+        if p.is_multiple_of(BASES[i]) {
+            return p == BASES[i];
+        }
+        i += 1;
+    }
+
+    // p - 1 = d * 2^s with d odd
+    let mut d = p - 1;
+    let mut s = 0;
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+
+    let mut i = 0;
+    while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, p);
+        if x != 1 && x != p - 1 {
+            let mut r = 1;
+            while r < s && x != p - 1 {
+                x = mul_mod(x, x, p);
+                r += 1;
+            }
+            if x != p - 1 {
+                return false;
+            }
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<const P: u64> Mod<P> {
+    const PRIME_CHECK: () = assert!(is_prime(P), "Modulus of Mod<P> must be a prime");
+
+    pub const fn new(value: u64) -> Self {
+        let () = Self::PRIME_CHECK;
+        Mod(value % P)
+    }
+
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(&self, exponent: u64) -> Self {
+        Mod(pow_mod(self.0, exponent, P))
+    }
+}
+
+// Through new() so that Mod::<4>::default() fails to compile like Mod::<4>::new(0)
+impl<const P: u64> Default for Mod<P> {
+    fn default() -> Self {
+        Mod::new(0)
+    }
+}
+
+impl<const P: u64> From<u64> for Mod<P> {
+    fn from(value: u64) -> Self {
+        Mod::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for Mod<P> {
+    fn from(value: i64) -> Self {
+        Mod::new((value as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+///
+/// Element traits
+///
+impl<const P: u64> MatrixElem for Mod<P> {
+
+    // u128 so that neither the sum nor the product can overflow for any 64 bit P
+    fn add(&self, rhs: &Self) -> Self {
+        Mod(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        Mod(mul_mod(self.0, rhs.0, P))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0 as f64)
+    }
+}
+
+impl<const P: u64> MatrixSub for Mod<P> {
+    fn sub(&self, rhs: &Self) -> Self {
+        Mod(((self.0 as u128 + P as u128 - rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> MatrixZeroOne for Mod<P> {
+    fn zero() -> Self {
+        Mod::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    fn one() -> Self {
+        Mod::new(1)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl<const P: u64> MatrixConstZeroOne for Mod<P> {
+    const ZERO: Self = Mod::new(0);
+    const ONE: Self = Mod::new(1);
+}
+
+impl<const P: u64> Ring for Mod<P> {}
+
+impl<const P: u64> Field for Mod<P> {
+
+    // There is no size in GF(p), any non-zero element is as good a pivot as any other
+    type Magnitude = bool;
+
+    fn div(&self, rhs: &Self) -> Self {
+        self.mul(&rhs.recip())
+    }
+
+    fn magnitude(&self) -> bool {
+        !self.is_zero()
+    }
+
+    fn negligible(_: &bool) -> bool {
+        false
+    }
+
+    // Fermat's little theorem: a^(p-1) = 1, so a^(p-2) = a^-1
+    fn recip(&self) -> Self {
+        if self.is_zero() {
+            panic!("Division by 0 in GF({})", P);
+        }
+
+        self.pow(P - 2)
+    }
+}
+
+///
+/// Operators
+///
+impl<const P: u64> ops::Add for Mod<P> {
+    type Output = Mod<P>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MatrixElem::add(&self, &rhs)
+    }
+}
+
+impl<const P: u64> ops::Sub for Mod<P> {
+    type Output = Mod<P>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        MatrixSub::sub(&self, &rhs)
+    }
+}
+
+impl<const P: u64> ops::Mul for Mod<P> {
+    type Output = Mod<P>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        MatrixElem::mul(&self, &rhs)
+    }
+}
+
+impl<const P: u64> ops::Div for Mod<P> {
+    type Output = Mod<P>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Field::div(&self, &rhs)
+    }
+}
+
+impl<const P: u64> ops::Neg for Mod<P> {
+    type Output = Mod<P>;
+
+    fn neg(self) -> Self::Output {
+        Ring::neg(&self)
+    }
+}
+
+impl<const P: u64> fmt::Display for Mod<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * Float functions that std provides as inherent methods but core doesn't. They forward to
+ * std when it's available and to libm otherwise. Called as Float::sqrt(x) so that the same
+ * code resolves to this trait in both builds.
+ */
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("mm needs either the std or the libm feature for its float functions");
+
+pub(crate) trait Float: Copy {
+    fn sqrt(self) -> Self;
+    fn powi(self, exponent: i32) -> Self;
+    fn powf(self, exponent: Self) -> Self;
+    fn exp(self) -> Self;
+    fn tanh(self) -> Self;
+    fn fract(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($type:ty, $sqrt:ident, $pow:ident, $exp:ident, $tanh:ident, $trunc:ident, $atan2:ident);* $(;)*) => {$(
+        #[cfg(feature = "std")]
+        impl Float for $type {
+            fn sqrt(self) -> Self {
+                <$type>::sqrt(self)
+            }
+
+            fn powi(self, exponent: i32) -> Self {
+                <$type>::powi(self, exponent)
+            }
+
+            fn powf(self, exponent: Self) -> Self {
+                <$type>::powf(self, exponent)
+            }
+
+            fn exp(self) -> Self {
+                <$type>::exp(self)
+            }
+
+            fn tanh(self) -> Self {
+                <$type>::tanh(self)
+            }
+
+            fn fract(self) -> Self {
+                <$type>::fract(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                <$type>::atan2(self, other)
+            }
+        }
+
+        #[cfg(all(not(feature = "std"), feature = "libm"))]
+        impl Float for $type {
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            fn powi(self, exponent: i32) -> Self {
+                libm::$pow(self, exponent as $type)
+            }
+
+            fn powf(self, exponent: Self) -> Self {
+                libm::$pow(self, exponent)
+            }
+
+            fn exp(self) -> Self {
+                libm::$exp(self)
+            }
+
+            fn tanh(self) -> Self {
+                libm::$tanh(self)
+            }
+
+            fn fract(self) -> Self {
+                self - libm::$trunc(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+        }
+    )*};
+}
+
+impl_float!(
+    f32, sqrtf, powf, expf, tanhf, truncf, atan2f;
+    f64, sqrt, pow, exp, tanh, trunc, atan2;
+);
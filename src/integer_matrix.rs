@@ -0,0 +1,262 @@
+use crate::{matrix::*, scalar::*};
+
+// U * A = H, U is unimodular (integer with det = +-1)
+pub struct HNFResult<T> {
+    pub h: Matrix<T>,
+    pub u: Matrix<T>
+}
+
+// U * A * V = D, U and V are unimodular
+pub struct SNFResult<T> {
+    pub d: Matrix<T>,
+    pub u: Matrix<T>,
+    pub v: Matrix<T>
+}
+
+// Every integer solution of Ax = b is particular + kernel * k for an integer vector k
+pub struct DiophantineSolution<T> {
+    pub particular: Matrix<T>,
+    pub kernel: Matrix<T>
+}
+
+// row_target -= q * row_source
+pub(crate) fn sub_row_multiple<T: Integer>(a: &mut Matrix<T>, target: usize, source: usize, q: &T) {
+    for col in 0..a.width() {
+        a[(target, col)] = a[(target, col)].sub(&q.mul(&a[(source, col)]));
+    }
+}
+
+// col_target -= q * col_source
+fn sub_col_multiple<T: Integer>(a: &mut Matrix<T>, target: usize, source: usize, q: &T) {
+    for row in 0..a.height() {
+        a[(row, target)] = a[(row, target)].sub(&q.mul(&a[(row, source)]));
+    }
+}
+
+fn negate_row<T: Integer>(a: &mut Matrix<T>, row: usize) {
+    for col in 0..a.width() {
+        a[(row, col)] = a[(row, col)].neg();
+    }
+}
+
+// Quotient rounded towards -inf, so that the remainder is non-negative for positive b
+fn floor_div<T: Integer>(a: &T, b: &T) -> T {
+    let (q, r) = a.div_rem(b);
+    if r < T::zero() { q.sub(&T::one()) } else { q }
+}
+
+/*
+ * Row-style Hermite normal form: H is in row echelon form, every pivot is positive
+ * and the entries above a pivot are reduced into [0, pivot)
+ */
+pub fn hermite_normal_form<T: Integer>(a: &Matrix<T>) -> HNFResult<T> {
+    let mut h = a.clone();
+    let mut u: Matrix<T> = Matrix::identity(a.height());
+
+    let mut row = 0;
+    for col in 0..h.width() {
+        if row == h.height() {
+            break;
+        }
+
+        // Euclid's algorithm on the rows - keep the smallest entry on top and reduce the others by it
+        loop {
+            let smallest = (row..h.height())
+                .filter(|&i| !h[(i, col)].is_zero())
+                .min_by_key(|&i| h[(i, col)].abs());
+
+            let Some(pivot_row) = smallest else {
+                break;
+            };
+
+            h.swap_rows(row, pivot_row);
+            u.swap_rows(row, pivot_row);
+
+            let mut done = true;
+            for i in (row + 1)..h.height() {
+                let q = h[(i, col)].div_rem(&h[(row, col)]).0;
+                sub_row_multiple(&mut h, i, row, &q);
+                sub_row_multiple(&mut u, i, row, &q);
+                done &= h[(i, col)].is_zero();
+            }
+
+            if done {
+                break;
+            }
+        }
+
+        // If there are only 0s in the column, skip it - col is incremented but row is not
+        let pivot = h.get(row, col);
+        if pivot.is_zero() {
+            continue;
+        }
+
+        if pivot < T::zero() {
+            negate_row(&mut h, row);
+            negate_row(&mut u, row);
+        }
+
+        // Reduce everything above the pivot
+        let pivot = h.get(row, col);
+        for i in 0..row {
+            let q = floor_div(&h[(i, col)], &pivot);
+            sub_row_multiple(&mut h, i, row, &q);
+            sub_row_multiple(&mut u, i, row, &q);
+        }
+
+        row += 1;
+    }
+
+    HNFResult { h, u }
+}
+
+/*
+ * Smith normal form: D is diagonal with non-negative entries, each dividing the next one
+ */
+pub fn smith_normal_form<T: Integer>(a: &Matrix<T>) -> SNFResult<T> {
+    let mut d = a.clone();
+    let mut u: Matrix<T> = Matrix::identity(a.height());
+    let mut v: Matrix<T> = Matrix::identity(a.width());
+
+    for t in 0..d.height().min(d.width()) {
+        loop {
+
+            // Move the smallest non-zero entry of the remaining submatrix to (t, t)
+            let mut smallest: Option<(usize, usize, T)> = None;
+            for i in t..d.height() {
+                for j in t..d.width() {
+                    let value = d[(i, j)].abs();
+                    if !value.is_zero() && smallest.as_ref().is_none_or(|s| value < s.2) {
+                        smallest = Some((i, j, value));
+                    }
+                }
+            }
+
+            // Everything left is 0, D is done
+            let Some((pivot_row, pivot_col, _)) = smallest else {
+                return SNFResult { d, u, v };
+            };
+
+            d.swap_rows(t, pivot_row);
+            u.swap_rows(t, pivot_row);
+            d.swap_cols(t, pivot_col);
+            v.swap_cols(t, pivot_col);
+
+            // Clear the column and the row of the pivot, any remainder becomes the next pivot
+            let pivot = d.get(t, t);
+            let mut clean = true;
+            for i in (t + 1)..d.height() {
+                let q = d[(i, t)].div_rem(&pivot).0;
+                sub_row_multiple(&mut d, i, t, &q);
+                sub_row_multiple(&mut u, i, t, &q);
+                clean &= d[(i, t)].is_zero();
+            }
+
+            for j in (t + 1)..d.width() {
+                let q = d[(t, j)].div_rem(&pivot).0;
+                sub_col_multiple(&mut d, j, t, &q);
+                sub_col_multiple(&mut v, j, t, &q);
+                clean &= d[(t, j)].is_zero();
+            }
+
+            if !clean {
+                continue;
+            }
+
+            // Pivot has to divide everything after it, if it doesn't pull the offending row in and repeat
+            let offending = (t + 1..d.height())
+                .find(|&i| (t + 1..d.width()).any(|j| !d[(i, j)].div_rem(&pivot).1.is_zero()));
+
+            match offending {
+                Some(i) => {
+                    sub_row_multiple(&mut d, t, i, &T::one().neg());
+                    sub_row_multiple(&mut u, t, i, &T::one().neg());
+                },
+                None => break
+            }
+        }
+
+        if d.get(t, t) < T::zero() {
+            negate_row(&mut d, t);
+            negate_row(&mut u, t);
+        }
+    }
+
+    SNFResult { d, u, v }
+}
+
+/*
+ * Fraction-free (Bareiss) elimination - every division is exact so there are no fractions and no rounding
+ */
+pub fn integer_det<T: Integer>(a: &Matrix<T>) -> T {
+    if a.height() != a.width() {
+        panic!("Cannot compute determinant of a non-square matrix! A=[{},{}]", a.height(), a.width());
+    }
+
+    let size = a.height();
+    if size == 0 {
+        return T::one();
+    }
+
+    let mut m = a.clone();
+    let mut negative = false;
+    let mut previous = T::one();
+
+    for k in 0..size - 1 {
+
+        // Any non-zero pivot will do
+        if m[(k, k)].is_zero() {
+            match ((k + 1)..size).find(|&i| !m[(i, k)].is_zero()) {
+                Some(i) => {
+                    m.swap_rows(k, i);
+                    negative = !negative;
+                },
+                None => return T::zero()
+            }
+        }
+
+        let pivot = m.get(k, k);
+        for i in (k + 1)..size {
+            for j in (k + 1)..size {
+                let value = m[(i, j)].mul(&pivot).sub(&m[(i, k)].mul(&m[(k, j)]));
+                m[(i, j)] = value.div_rem(&previous).0;
+            }
+        }
+        previous = pivot;
+    }
+
+    let det = m.get(size - 1, size - 1);
+    if negative { det.neg() } else { det }
+}
+
+/*
+ * Integer solutions of Ax = b using the Smith normal form, None if there are none
+ */
+pub fn solve_diophantine<T: Integer>(a: &Matrix<T>, b: &Matrix<T>) -> Option<DiophantineSolution<T>> {
+    if b.width() != 1 || b.height() != a.height() {
+        panic!("b must be a column vector with the same height as A, A=[{},{}], b=[{},{}]", a.height(), a.width(), b.height(), b.width());
+    }
+
+    // UAV = D, so Ax = b becomes Dy = Ub with x = Vy
+    let snf = smith_normal_form(a);
+    let c = &snf.u * b;
+    let rank = (0..a.height().min(a.width())).take_while(|&i| !snf.d[(i, i)].is_zero()).count();
+
+    let mut y = Matrix::new_fill(a.width(), 1, T::zero());
+    for i in 0..c.height() {
+        if i < rank {
+            let (q, r) = c[(i, 0)].div_rem(&snf.d[(i, i)]);
+            if !r.is_zero() {
+                return None;
+            }
+            y[(i, 0)] = q;
+        }
+        else if !c[(i, 0)].is_zero() {
+            return None;
+        }
+    }
+
+    // Columns of V past the rank are mapped to 0 by A
+    let kernel = if rank < a.width() { snf.v.cut(.., rank..) } else { Matrix::with_capacity(a.width(), 0) };
+    Some(DiophantineSolution { particular: &snf.v * &y, kernel })
+}
@@ -0,0 +1,275 @@
+use crate::{lu_decomposition::*, matrix::*, matrix_traits::*, scalar::*};
+use core::{fmt, ops};
+
+// Floats that can be stepped to the next representable value - what outward rounding is built on
+pub trait IntervalBound: RealField + Copy {
+    fn next_up(self) -> Self;
+    fn next_down(self) -> Self;
+}
+
+macro_rules! impl_interval_bound {
+    ($($type:ty),* $(,)*) => {$(
+        impl IntervalBound for $type {
+            fn next_up(self) -> Self {
+                <$type>::next_up(self)
+            }
+
+            fn next_down(self) -> Self {
+                <$type>::next_down(self)
+            }
+        }
+    )*};
+}
+
+impl_interval_bound!(f32, f64);
+
+/*
+ * Closed interval [lo, hi] that is guaranteed to contain the true value.
+ * Every operation rounds the lower bound down and the upper bound up by one ulp,
+ * so rounding errors of the floating point operations can only make it wider.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T
+}
+
+impl<T: IntervalBound> Interval<T> {
+    pub fn new(lo: T, hi: T) -> Self {
+        if lo > hi {
+            panic!("Lower bound of an interval can't be above the upper bound");
+        }
+
+        Interval { lo, hi }
+    }
+
+    pub fn point(value: T) -> Self {
+        Interval { lo: value, hi: value }
+    }
+
+    // Smallest interval containing x +- radius
+    pub fn around(value: T, radius: T) -> Self {
+        let radius = radius.abs();
+        Interval { lo: value.sub(&radius).next_down(), hi: value.add(&radius).next_up() }
+    }
+
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    pub fn mid(&self) -> T {
+        self.lo.add(&self.hi.sub(&self.lo).div(&T::from_f64(2.)))
+    }
+
+    pub fn width(&self) -> T {
+        self.hi.sub(&self.lo).next_up()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    pub fn contains_zero(&self) -> bool {
+        self.contains(T::zero())
+    }
+
+    // Smallest interval containing both
+    pub fn hull(&self, other: &Self) -> Self {
+        let lo = if other.lo < self.lo { other.lo } else { self.lo };
+        let hi = if other.hi > self.hi { other.hi } else { self.hi };
+        Interval { lo, hi }
+    }
+
+    // Smallest |x| over the interval, 0 if it contains 0
+    pub fn mignitude(&self) -> T {
+        if self.contains_zero() {
+            return T::zero();
+        }
+
+        let (lo, hi) = (self.lo.abs(), self.hi.abs());
+        if lo < hi { lo } else { hi }
+    }
+
+    // Biggest |x| over the interval
+    pub fn magnitude(&self) -> T {
+        let (lo, hi) = (self.lo.abs(), self.hi.abs());
+        if lo > hi { lo } else { hi }
+    }
+
+    fn rounded(lo: T, hi: T) -> Self {
+        Interval { lo: lo.next_down(), hi: hi.next_up() }
+    }
+}
+
+impl<T: IntervalBound> From<T> for Interval<T> {
+    fn from(value: T) -> Self {
+        Interval::point(value)
+    }
+}
+
+///
+/// Element traits
+///
+impl<T: IntervalBound> MatrixElem for Interval<T> {
+    fn add(&self, rhs: &Self) -> Self {
+        Interval::rounded(self.lo.add(&rhs.lo), self.hi.add(&rhs.hi))
+    }
+
+    // Extremes of the product are at the endpoints
+    fn mul(&self, rhs: &Self) -> Self {
+        let products = [self.lo.mul(&rhs.lo), self.lo.mul(&rhs.hi), self.hi.mul(&rhs.lo), self.hi.mul(&rhs.hi)];
+        let mut lo = products[0];
+        let mut hi = products[0];
+        for product in &products[1..] {
+            if *product < lo {
+                lo = *product;
+            }
+            if *product > hi {
+                hi = *product;
+            }
+        }
+        Interval::rounded(lo, hi)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.mid().to_f64()
+    }
+}
+
+impl<T: IntervalBound> MatrixSub for Interval<T> {
+    fn sub(&self, rhs: &Self) -> Self {
+        Interval::rounded(self.lo.sub(&rhs.hi), self.hi.sub(&rhs.lo))
+    }
+}
+
+impl<T: IntervalBound> MatrixZeroOne for Interval<T> {
+    fn zero() -> Self {
+        Interval::point(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+
+    fn one() -> Self {
+        Interval::point(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.lo.is_one() && self.hi.is_one()
+    }
+}
+
+impl<T: IntervalBound + MatrixConstZeroOne> MatrixConstZeroOne for Interval<T> {
+    const ZERO: Self = Interval { lo: T::ZERO, hi: T::ZERO };
+    const ONE: Self = Interval { lo: T::ONE, hi: T::ONE };
+}
+
+// Negation is exact, no rounding needed
+impl<T: IntervalBound> Ring for Interval<T> {
+    fn neg(&self) -> Self {
+        Interval { lo: self.hi.neg(), hi: self.lo.neg() }
+    }
+}
+
+impl<T: IntervalBound> Field for Interval<T> {
+
+    // Pivots are compared by mignitude, so an interval containing 0 is never picked
+    type Magnitude = T;
+
+    fn div(&self, rhs: &Self) -> Self {
+        self.mul(&rhs.recip())
+    }
+
+    fn magnitude(&self) -> T {
+        self.mignitude()
+    }
+
+    fn negligible(_: &T) -> T {
+        T::zero()
+    }
+
+    fn recip(&self) -> Self {
+        if self.contains_zero() {
+            panic!("Division by an interval containing 0");
+        }
+
+        Interval::rounded(T::one().div(&self.hi), T::one().div(&self.lo))
+    }
+}
+
+///
+/// Enclosures
+///
+pub fn to_interval_matrix<T: IntervalBound>(a: &Matrix<T>) -> Matrix<Interval<T>> {
+    a.map(|&value| Interval::point(value))
+}
+
+/*
+ * Interval Gaussian elimination - the result contains the solution of Ax = b for every A and b
+ * inside the given intervals. Panics if no pivot can be found that is bounded away from 0.
+ */
+pub fn interval_lu_solve<T: IntervalBound>(a: &Matrix<Interval<T>>, b: &Matrix<Interval<T>>) -> Matrix<Interval<T>> {
+    if a.height() != a.width() {
+        panic!("Cannot solve a non-square system! A=[{},{}]", a.height(), a.width());
+    }
+
+    lu_solve(&lu_gauss(a), b)
+}
+
+///
+/// Operators
+///
+impl<T: IntervalBound> ops::Add for Interval<T> {
+    type Output = Interval<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MatrixElem::add(&self, &rhs)
+    }
+}
+
+impl<T: IntervalBound> ops::Sub for Interval<T> {
+    type Output = Interval<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        MatrixSub::sub(&self, &rhs)
+    }
+}
+
+impl<T: IntervalBound> ops::Mul for Interval<T> {
+    type Output = Interval<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        MatrixElem::mul(&self, &rhs)
+    }
+}
+
+impl<T: IntervalBound> ops::Div for Interval<T> {
+    type Output = Interval<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Field::div(&self, &rhs)
+    }
+}
+
+impl<T: IntervalBound> ops::Neg for Interval<T> {
+    type Output = Interval<T>;
+
+    fn neg(self) -> Self::Output {
+        Ring::neg(&self)
+    }
+}
+
+// Prints as [lo, hi], precision is applied to both bounds
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "[{:.*}, {:.*}]", precision, self.lo, precision, self.hi),
+            None => write!(f, "[{}, {}]", self.lo, self.hi)
+        }
+    }
+}
@@ -1,5 +1,37 @@
-mod matrix_traits;
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+mod float;
+
+// The parts of std's prelude that come from alloc, so modules work the same with and without std
+mod prelude {
+    pub use alloc::{borrow::ToOwned, boxed::Box, format, string::{String, ToString}, vec, vec::Vec};
+}
+
+pub mod matrix_traits;
 pub mod matrix;
+pub mod static_matrix;
+pub mod elementwise;
+pub mod statistics;
+pub mod norms;
+pub mod vector;
+pub mod shape;
+pub mod scalar;
+pub mod semiring;
+pub mod complex;
+pub mod rational;
+pub mod finite_field;
+pub mod interval;
+pub mod dual;
+pub mod tape;
+pub mod expr;
+pub mod bigint;
+pub mod integer_matrix;
+pub mod lll;
 pub mod qr_factorization;
 pub mod lu_decomposition;
-pub mod rref;
\ No newline at end of file
+pub mod cofactor;
+pub mod rref;
+pub mod bit_matrix;
+pub mod triangular_solve;
+pub mod structured;
\ No newline at end of file
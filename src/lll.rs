@@ -0,0 +1,137 @@
+use crate::{integer_matrix::sub_row_multiple, matrix::*, matrix_traits::*, prelude::*, rational::*, scalar::*};
+
+// Rows of orthogonal are the Gram-Schmidt vectors, mu is unit lower triangular with A = mu * orthogonal
+pub struct GramSchmidtResult<T> {
+    pub orthogonal: Matrix<Ratio<T>>,
+    pub mu: Matrix<Ratio<T>>,
+    pub norms_sqr: Vec<Ratio<T>>
+}
+
+// transform * A = basis, transform is unimodular
+pub struct LLLResult<T> {
+    pub basis: Matrix<T>,
+    pub transform: Matrix<T>
+}
+
+fn dot<T: Integer>(a: &[Ratio<T>], b: &[Ratio<T>]) -> Ratio<T> {
+    a.iter().zip(b).fold(Ratio::zero(), |acc, (x, y)| acc.add(&x.mul(y)))
+}
+
+/*
+ * Gram-Schmidt orthogonalization of the rows, without normalization so that it stays exact
+ */
+pub fn gram_schmidt<T: Integer>(a: &Matrix<T>) -> GramSchmidtResult<T> {
+    let (height, width) = (a.height(), a.width());
+    let mut orthogonal: Vec<Vec<Ratio<T>>> = Vec::with_capacity(height);
+    let mut mu = Matrix::new_fill(height, height, Ratio::zero());
+    let mut norms_sqr: Vec<Ratio<T>> = Vec::with_capacity(height);
+
+    for i in 0..height {
+        let row: Vec<Ratio<T>> = (0..width).map(|c| Ratio::from(a.get(i, c))).collect();
+        let mut current = row.clone();
+
+        // b*_i = b_i - sum mu_ij b*_j, mu_ij = <b_i, b*_j> / |b*_j|^2
+        for j in 0..i {
+            let coefficient = dot(&row, &orthogonal[j]).div(&norms_sqr[j]);
+            for (value, other) in current.iter_mut().zip(&orthogonal[j]) {
+                *value = value.sub(&coefficient.mul(other));
+            }
+            mu[(i, j)] = coefficient;
+        }
+        mu[(i, i)] = Ratio::one();
+
+        let norm_sqr = dot(&current, &current);
+        if norm_sqr.is_zero() {
+            panic!("Rows of the basis must be linearly independent! Row {} depends on the previous ones", i);
+        }
+
+        norms_sqr.push(norm_sqr);
+        orthogonal.push(current);
+    }
+
+    let mut ret = Matrix::new_fill(height, width, Ratio::zero());
+    for (r, row) in orthogonal.into_iter().enumerate() {
+        for (c, value) in row.into_iter().enumerate() {
+            ret[(r, c)] = value;
+        }
+    }
+
+    GramSchmidtResult { orthogonal: ret, mu, norms_sqr }
+}
+
+/*
+ * Gram-Schmidt data after swapping rows k - 1 and k, only those two b* change so mu and |b*|^2
+ * are updated directly instead of orthogonalizing the whole basis again
+ */
+fn swap_update<T: Integer>(mu: &mut Matrix<Ratio<T>>, norms_sqr: &mut [Ratio<T>], k: usize) {
+    let old_mu = mu[(k, k - 1)].clone();
+    let norm_sqr = norms_sqr[k].add(&old_mu.mul(&old_mu).mul(&norms_sqr[k - 1]));
+
+    // New b*_k-1 is the old b*_k + mu b*_k-1
+    let new_mu = old_mu.mul(&norms_sqr[k - 1]).div(&norm_sqr);
+    norms_sqr[k] = norms_sqr[k - 1].mul(&norms_sqr[k]).div(&norm_sqr);
+    norms_sqr[k - 1] = norm_sqr;
+    mu[(k, k - 1)] = new_mu.clone();
+
+    // Coefficients against earlier vectors just move with their rows
+    for j in 0..(k - 1) {
+        let value = mu[(k, j)].clone();
+        mu[(k, j)] = mu[(k - 1, j)].clone();
+        mu[(k - 1, j)] = value;
+    }
+
+    // Later rows are expressed in the new pair of b*
+    for i in (k + 1)..mu.height() {
+        let value = mu[(i, k)].clone();
+        mu[(i, k)] = mu[(i, k - 1)].sub(&old_mu.mul(&value));
+        mu[(i, k - 1)] = value.add(&new_mu.mul(&mu[(i, k)]));
+    }
+}
+
+/*
+ * Lenstra-Lenstra-Lovasz reduction of the lattice spanned by the rows of A.
+ * delta has to be in (1/4, 1], 3/4 is the usual choice - higher gives a better basis but takes longer.
+ */
+pub fn lll<T: Integer>(a: &Matrix<T>, delta: Ratio<T>) -> LLLResult<T> {
+    let quarter = Ratio::new(T::one(), T::one().add(&T::one()).mul(&T::one().add(&T::one())));
+    if delta <= quarter || delta > Ratio::one() {
+        panic!("LLL delta must be in (1/4, 1]!");
+    }
+
+    let mut basis = a.clone();
+    let mut transform: Matrix<T> = Matrix::identity(a.height());
+    let GramSchmidtResult { mut mu, mut norms_sqr, .. } = gram_schmidt(&basis);
+
+    let mut k = 1;
+    while k < basis.height() {
+
+        // Size reduction - make |mu_kj| <= 1/2, mu is updated in place as b*_k doesn't change
+        for j in (0..k).rev() {
+            let q = mu[(k, j)].round();
+            if q.is_zero() {
+                continue;
+            }
+
+            sub_row_multiple(&mut basis, k, j, &q);
+            sub_row_multiple(&mut transform, k, j, &q);
+            let q = Ratio::from(q);
+            for i in 0..=j {
+                mu[(k, i)] = mu[(k, i)].sub(&q.mul(&mu[(j, i)]));
+            }
+        }
+
+        // Lovasz condition: |b*_k|^2 >= (delta - mu_k,k-1^2) |b*_k-1|^2
+        let last = &mu[(k, k - 1)];
+        if norms_sqr[k] >= delta.sub(&last.mul(last)).mul(&norms_sqr[k - 1]) {
+            k += 1;
+        }
+        else {
+            basis.swap_rows(k, k - 1);
+            transform.swap_rows(k, k - 1);
+            swap_update(&mut mu, &mut norms_sqr, k);
+            k = (k - 1).max(1);
+        }
+    }
+
+    LLLResult { basis, transform }
+}
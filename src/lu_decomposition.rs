@@ -1,115 +1,131 @@
-use crate::matrix::*;
+use crate::{matrix::*, scalar::*, structured::*, triangular_solve::*};
+use core::ops;
 
-pub struct LUResult {
-    pub l: Matrix<f64>,
-    pub u: Matrix<f64>,
-    pub p: Matrix<f64>
+pub struct LUResult<T> {
+    pub l: LowerTriangular<T>,
+    pub u: UpperTriangular<T>,
+    pub p: Permutation
 }
 
-pub fn lu_gauss(a: &Matrix<f64>) -> LUResult {
+// What Gaussian elimination needs from its working matrices, so that SMatrix runs the same code without allocating
+pub(crate) trait EliminationStorage<T>: ops::IndexMut<(usize, usize), Output = T> {
+    fn swap_rows(&mut self, row_1: usize, row_2: usize);
+}
+
+impl<T: Clone> EliminationStorage<T> for Matrix<T> {
+    fn swap_rows(&mut self, row_1: usize, row_2: usize) {
+        Matrix::swap_rows(self, row_1, row_2);
+    }
+}
+
+pub fn lu_gauss<T: Field>(a: &Matrix<T>) -> LUResult<T> {
 
     // Setup L - sqare of size of height of A
-    let mut l: Matrix<f64> = Matrix::new_fill(a.height(), a.height(), 0.);
+    let mut l: Matrix<T> = Matrix::new_fill(a.height(), a.height(), T::zero());
 
     // Setup U - same size as A, is our working matrix
-    let mut u: Matrix<f64> = a.clone();
+    let mut u: Matrix<T> = a.clone();
 
     // Setup P - permutation matrix
-    let mut p: Matrix<f64> = Matrix::identity(a.height());
+    let mut p = Permutation::identity(a.height());
+
+    eliminate(&mut u, &mut l, a.height(), a.width(), |row_1, row_2| p.swap(row_1, row_2));
+    LUResult { l: LowerTriangular::from_matrix(&l), u: UpperTriangular::from_matrix(&u), p }
+}
+
+/*
+ * Gaussian elimination with partial pivoting in place. U starts as A (height x width), L as a height x height
+ * matrix of 0s and swap is called for every pair of rows swapped. Shared by lu_gauss and SMatrix::lu
+ */
+pub(crate) fn eliminate<T: Field, S: EliminationStorage<T>>(u: &mut S, l: &mut S, height: usize, width: usize, mut swap: impl FnMut(usize, usize)) {
+
+    // Tolerance is relative to the largest entry of A
+    let scale = (0..height)
+        .flat_map(|row| (0..width).map(move |col| (row, col)))
+        .map(|index| u[index].magnitude())
+        .fold(T::zero().magnitude(), |acc, value| if value > acc { value } else { acc });
+    let negligible = T::negligible(&scale);
 
     // Each row is the basis for Gaussian Elimination
-    let iterations = u.width().min(u.height());
+    let iterations = width.min(height);
     for row in 0..iterations {
 
         // Find the row to pivot to the top - with highest element in the column
-        let mut biggest = (0, 0.);
-        for pivot_row in row..u.height() {
-            let val = &u.get(pivot_row, row);
-            if val.abs() > biggest.1 {
-                biggest = (pivot_row, val.abs());
+        let mut biggest: Option<(usize, T::Magnitude)> = None;
+        for pivot_row in row..height {
+            let val = u[(pivot_row, row)].magnitude();
+            if val > negligible && biggest.as_ref().is_none_or(|b| val > b.1) {
+                biggest = Some((pivot_row, val));
             }
         }
 
-        // If there are only "0s" in column, skip it
-        if biggest.1 <= f64::EPSILON {
+        // If there are only "0s" in column, skip it - for floats what's left is rounding noise, it's flushed so
+        // that U is exactly upper triangular (nothing is lost in from_matrix) with a true 0 pivot
+        let Some((pivot_row, _)) = biggest else {
+            for row_index in row..height {
+                u[(row_index, row)] = u[(row_index, row)].flush_negligible();
+            }
             continue;
-        }
+        };
 
         // Swap two rows and save the permutation
-        u.swap_rows(row, biggest.0);
-        p.swap_rows(row, biggest.0);
-        l.swap_rows(row, biggest.0);
+        u.swap_rows(row, pivot_row);
+        swap(row, pivot_row);
+        l.swap_rows(row, pivot_row);
 
         // Grab the first number in the row (which is on diagonal because all prior are 0)
-        let pivot = u.get(row, row);
+        let pivot = u[(row, row)].clone();
 
-        for row_index in (row + 1)..u.height() {
+        for row_index in (row + 1)..height {
 
             // Compute quotient between pivot and every number in the column below it
-            let quotient = u.get(row_index, row) / pivot;
-
-            // Save it to L in the same position as in U
-            l.set(row_index, row, quotient);
+            let quotient = u[(row_index, row)].div(&pivot);
 
             // Substract the whole row above * quotient from current row
-            for col_index in row..u.width() {
-                u.set(row_index, col_index, u.get(row_index, col_index) - quotient * u.get(row, col_index));
+            for col_index in row..width {
+                u[(row_index, col_index)] = u[(row_index, col_index)].sub(&quotient.mul(&u[(row, col_index)]));
             }
+
+            // Save it to L in the same position as in U
+            l[(row_index, row)] = quotient;
         }
     }
 
-    LUResult { l: Matrix::identity(l.height()) + l, u: u, p: p }
+    // L has ones on the diagonal
+    for row in 0..height {
+        l[(row, row)] = T::one();
+    }
 }
 
-pub fn lu_solve(lu: &LUResult, b: &Matrix<f64>) -> Matrix<f64> {
-    if b.width() != 1 {
-        panic!("b must be in form of a column vector, b=[{},{}]", b.height(), b.height());
+pub fn lu_solve<T: Field>(lu: &LUResult<T>, b: &Matrix<T>) -> Matrix<T> {
+    if b.height() != lu.p.size() {
+        panic!("b must have the same height as A, A=[{},{}], b=[{},{}]", lu.p.size(), lu.u.width(), b.height(), b.width());
     }
 
     // PA = LU, Ax = b, so LUx = Pb. If y = Ux, then Ly = Pb
-    // Step 1. Solve Ly = Pb for y using forward substitution
-    let mut y = Matrix::new_fill(b.height(), b.width(), 0.);
-    let b = &lu.p * b;
-
-    for row in 0..y.height() {
-        let mut new_y = b.get(row, 0);
-
-        for col in 0..row {
-            new_y -= y.get(col, 0) * lu.l.get(row, col); 
-        }
-
-        y.set(row, 0, new_y);
-    }
+    // Step 1. Solve Ly = Pb for y using forward substitution, L has ones on the diagonal
+    let y = solve_lower_triangular(&lu.l, &(&lu.p * b), TriangularSolveOptions { unit_diagonal: true, ..Default::default() });
 
     // Step 2. Solve Ux = y for x using backward substitution
-    let mut x = Matrix::new_fill(y.height(), y.width(), 0.);
-
-    for row in (0..y.height()).rev() {
-        let mut new_x = y.get(row, 0);
-
-        for col in ((row + 1)..y.height()).rev() {
-            new_x -= x.get(col, 0) * lu.u.get(row, col); 
-        }
-
-        new_x /= lu.u.get(row, row);
-
-        x.set(row, 0, new_x);
-    }
-
-    x
+    solve_upper_triangular(&lu.u, &y, TriangularSolveOptions::default())
 }
 
-pub fn inv(a: &Matrix<f64>) -> Matrix<f64> {
+pub fn inv<T: Field>(a: &Matrix<T>) -> Matrix<T> {
     if a.height() != a.width() {
         panic!("Cannot inverse a non-square matrix! A=[{},{}]", a.height(), a.width());
     }
 
-    let lu = lu_gauss(&a);
-    let mut res: Matrix<f64> = Matrix::identity(a.height());
+    // Every column of the identity is solved for at once
+    lu_solve(&lu_gauss(a), &Matrix::identity(a.height()))
+}
 
-    for col in 0..a.width() {
-        res.set_matrix(&lu_solve(&lu, &res.cut(.., col..col+1)), 0, col);
+pub fn det<T: Field>(a: &Matrix<T>) -> T {
+    if a.height() != a.width() {
+        panic!("Cannot compute determinant of a non-square matrix! A=[{},{}]", a.height(), a.width());
     }
 
-    res
-}
\ No newline at end of file
+    // det(P)det(A) = det(L)det(U), det(L) = 1 and det(P) = det(P^-1) = +-1
+    let lu = lu_gauss(a);
+    let det = lu.u.determinant();
+    if lu.p.determinant() < 0 { det.neg() } else { det }
+}
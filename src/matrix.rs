@@ -1,5 +1,5 @@
-use crate::matrix_traits::*;
-use std::{fmt::{self}, ops::{self, RangeBounds}};
+use crate::{float::Float, matrix_traits::*, prelude::*, scalar::*};
+use core::{fmt::{self}, iter, ops::{self, RangeBounds}, slice};
 
 pub struct Matrix<T> {
     rows: Vec<Vec<T>>
@@ -15,10 +15,13 @@ impl<T: Clone, const M: usize, const N: usize> From<[[T; M]; N]> for Matrix<T> {
     }
 }
 
+// Text elements only concatenate, a+b times c comes out as "a+bc" - Expr keeps the structure
+#[deprecated(note = "Matrix<String> gives ambiguous results, build a Matrix<Expr> with Expr::var instead")]
 pub trait FromStr<T> : Sized {
     fn from_str(_: T) -> Self;
 }
 
+#[allow(deprecated)]
 impl<const M: usize, const N: usize> FromStr<[[&str; M]; N]> for Matrix<String> {
     fn from_str(arr: [[&str; M]; N]) -> Self {
         let mut m: Matrix<String> = Matrix::with_capacity(N, M);
@@ -48,25 +51,25 @@ impl<T: Clone> Matrix<T> {
 
     pub fn cut<R: RangeBounds<usize>, C: RangeBounds<usize>>(&self, rows: R, cols: C) -> Self {
         let row_start = match rows.start_bound() {
-            ops::Bound::Included(x) => x.clone(),
+            ops::Bound::Included(x) => *x,
             ops::Bound::Excluded(x) => x + 1,
             ops::Bound::Unbounded => 0
         };
 
         let row_end = match rows.end_bound() {
-            ops::Bound::Included(x) => x.clone(),
+            ops::Bound::Included(x) => *x,
             ops::Bound::Excluded(x) => x - 1,
             ops::Bound::Unbounded => self.height() - 1
         };
 
         let col_start = match cols.start_bound() {
-            ops::Bound::Included(x) => x.clone(),
+            ops::Bound::Included(x) => *x,
             ops::Bound::Excluded(x) => x + 1,
             ops::Bound::Unbounded => 0
         };
 
         let col_end = match cols.end_bound() {
-            ops::Bound::Included(x) => x.clone(),
+            ops::Bound::Included(x) => *x,
             ops::Bound::Excluded(x) => x - 1,
             ops::Bound::Unbounded => self.width() - 1
         };
@@ -92,11 +95,7 @@ impl<T: Clone> Matrix<T> {
     }
 
     pub fn set(&mut self, row_index: usize, col_index: usize, value: T) {
-        if row_index >= self.height() || col_index >= self.width() {
-            panic!("Index out of bounds! [{row_index}, {col_index}] / [{}, {}]", self.height(), self.width())
-        }
-        
-        self.rows[row_index][col_index] = value;
+        self[(row_index, col_index)] = value;
     }
 
     pub fn set_matrix(&mut self, matrix: &Matrix<T>, row_start: usize, col_start: usize) {
@@ -117,13 +116,7 @@ impl<T: Clone> Matrix<T> {
             return;
         }
         
-        for col in 0..self.width() {
-            unsafe {
-                let x = &mut self.rows[row_1][col] as *mut T;
-                let y = &mut self.rows[row_2][col] as *mut T;
-                std::mem::swap(&mut *x, &mut *y);
-            }
-        }
+        self.rows.swap(row_1, row_2);
     }
 
     pub fn get(&self, row_index: usize, col_index: usize) -> T {
@@ -131,6 +124,46 @@ impl<T: Clone> Matrix<T> {
     }
 }
 
+// A[(row, col)] - panics when out of bounds, same as set()
+impl<T> ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row_index, col_index): (usize, usize)) -> &Self::Output {
+        self.check_index(row_index, col_index);
+        &self.rows[row_index][col_index]
+    }
+}
+
+impl<T> ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row_index, col_index): (usize, usize)) -> &mut Self::Output {
+        self.check_index(row_index, col_index);
+        &mut self.rows[row_index][col_index]
+    }
+}
+
+// A[row] - the row as a slice, so its length can't be changed
+impl<T> ops::Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    fn index(&self, row_index: usize) -> &Self::Output {
+        if row_index >= self.height() {
+            panic!("Row index out of bounds! {row_index} / {}", self.height())
+        }
+
+        &self.rows[row_index]
+    }
+}
+
+impl<T> ops::IndexMut<usize> for Matrix<T> {
+    fn index_mut(&mut self, row_index: usize) -> &mut Self::Output {
+        if row_index >= self.height() {
+            panic!("Row index out of bounds! {row_index} / {}", self.height())
+        }
+
+        &mut self.rows[row_index]
+    }
+}
+
 impl<T: Clone> Clone for Matrix<T> {
     fn clone(&self) -> Self {
         Self { rows: self.rows.clone() }
@@ -182,54 +215,122 @@ impl<T: MatrixZeroOne> Matrix<T> {
     }
 }
 
-impl<T: Clone + MatrixElem> ops::Add for Matrix<T> {
-    type Output = Matrix<T>;
+///
+/// Operators - every owned/borrowed combination, an owned operand's buffer is reused for the result
+///
+impl<T: Clone> Matrix<T> {
+    // Sizes have to be equal or 1 in each dimension, the same rule as broadcast_map()
+    fn check_broadcast(&self, rhs: &Self, verb: &str, symbol: &str) {
+        if self.height() != rhs.height() && self.height() != 1 && rhs.height() != 1 {
+            panic!("Can't {verb} matrices A{symbol}B: A.height ({}) != B.height ({})", self.height(), rhs.height())
+        }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        if self.height() != rhs.height() {
-            panic!("Can't add matrices A+B: A.height ({}) != B.height ({})", self.height(), rhs.height())
+        if self.width() != rhs.width() && self.width() != 1 && rhs.width() != 1 {
+            panic!("Can't {verb} matrices A{symbol}B: A.width ({}) != B.width ({})", self.width(), rhs.width())
         }
+    }
 
-        if self.width() != rhs.width() {
-            panic!("Can't add matrices A+B: A.width ({}) != B.width ({})", self.width(), rhs.width())
+    fn same_size(&self, rhs: &Self) -> bool {
+        self.height() == rhs.height() && self.width() == rhs.width()
+    }
+
+    // a_ij = f(a_ij, b_ij)
+    fn zip_assign(&mut self, rhs: &Self, f: impl Fn(&T, &T) -> T) {
+        for (row, rhs_row) in self.rows.iter_mut().zip(&rhs.rows) {
+            for (value, rhs_value) in row.iter_mut().zip(rhs_row) {
+                *value = f(value, rhs_value);
+            }
         }
+    }
 
-        let mut ret = Matrix::with_capacity(self.height(), self.width());
-        for row_index in 0..self.height() {
-            for col_index in 0..self.width() {
-                ret.push_to_row(row_index, self.rows(row_index)[col_index].clone().add(rhs.rows(row_index)[col_index].clone()));
+    // b_ij = f(a_ij, b_ij), for when only the right operand can be reused
+    fn zip_into(&self, mut rhs: Self, f: impl Fn(&T, &T) -> T) -> Self {
+        for (row, rhs_row) in self.rows.iter().zip(rhs.rows.iter_mut()) {
+            for (value, rhs_value) in row.iter().zip(rhs_row.iter_mut()) {
+                *rhs_value = f(value, rhs_value);
             }
         }
-        ret
+        rhs
     }
 }
 
-impl<T: Clone + MatrixElem> ops::Sub for Matrix<T> {
-    type Output = Matrix<T>;
+// A+B, A-B, A/B and A+=B, A-=B, A/=B - elementwise, B is broadcast when it's a row or column vector or 1x1
+// and A can be too. A*B stays the matrix product
+macro_rules! elementwise_op_impl(
+    ($Trait: ident, $method: ident, $AssignTrait: ident, $assign_method: ident, $Bound: ident, $verb: literal, $symbol: literal) => {
+        impl<T: $Bound> ops::$AssignTrait<&Matrix<T>> for Matrix<T> {
+            fn $assign_method(&mut self, rhs: &Matrix<T>) {
+                self.check_broadcast(rhs, $verb, $symbol);
+                if self.same_size(rhs) {
+                    self.zip_assign(rhs, $Bound::$method);
+                    return;
+                }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        if self.height() != rhs.height() {
-            panic!("Can't sub matrices A-B: A.height ({}) != B.height ({})", self.height(), rhs.height())
+                // In place A keeps its size, so only B may be repeated
+                if rhs.height() > self.height() || rhs.width() > self.width() {
+                    panic!("Can't {} matrices A{}=B: B=[{},{}] doesn't broadcast to A=[{},{}]",
+                        $verb, $symbol, rhs.height(), rhs.width(), self.height(), self.width())
+                }
+                *self = self.broadcast_map(rhs, $Bound::$method);
+            }
         }
 
-        if self.width() != rhs.width() {
-            panic!("Can't sub matrices A-B: A.width ({}) != B.width ({})", self.width(), rhs.width())
+        impl<T: $Bound> ops::$AssignTrait for Matrix<T> {
+            fn $assign_method(&mut self, rhs: Matrix<T>) {
+                ops::$AssignTrait::$assign_method(self, &rhs);
+            }
         }
 
-        let mut ret = Matrix::with_capacity(self.height(), self.width());
-        for row_index in 0..self.height() {
-            for col_index in 0..self.width() {
-                ret.push_to_row(row_index, self.rows(row_index)[col_index].clone().sub(rhs.rows(row_index)[col_index].clone()));
+        impl<T: $Bound> ops::$Trait<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(mut self, rhs: &Matrix<T>) -> Self::Output {
+                if self.same_size(rhs) {
+                    self.zip_assign(rhs, $Bound::$method);
+                    return self;
+                }
+                ops::$Trait::$method(&self, rhs)
+            }
+        }
+
+        impl<T: $Bound> ops::$Trait for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Self::Output {
+                ops::$Trait::$method(self, &rhs)
+            }
+        }
+
+        impl<T: $Bound> ops::$Trait<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Self::Output {
+                if self.same_size(&rhs) {
+                    return self.zip_into(rhs, $Bound::$method);
+                }
+                ops::$Trait::$method(self, &rhs)
+            }
+        }
+
+        impl<T: $Bound> ops::$Trait for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Self::Output {
+                self.check_broadcast(rhs, $verb, $symbol);
+                self.broadcast_map(rhs, $Bound::$method)
             }
         }
-        ret
     }
-}
+);
+
+elementwise_op_impl!(Add, add, AddAssign, add_assign, MatrixElem, "add", "+");
+elementwise_op_impl!(Sub, sub, SubAssign, sub_assign, MatrixSub, "sub", "-");
+elementwise_op_impl!(Div, div, DivAssign, div_assign, Field, "divide", "/");
 
 // Scalar * Matrix
 macro_rules! left_scalar_mul_impl(
     ($($T: ty),* $(,)*) => {$(
-        impl std::ops::Mul<crate::matrix::Matrix<$T>> for $T {
+        impl core::ops::Mul<crate::matrix::Matrix<$T>> for $T {
             type Output = crate::matrix::Matrix<$T>;
         
             fn mul(self, rhs: crate::matrix::Matrix<$T>) -> Self::Output {
@@ -239,10 +340,25 @@ macro_rules! left_scalar_mul_impl(
     )*}
 );
 
-left_scalar_mul_impl!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+left_scalar_mul_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
 
 // Matrix * Scalar
-impl<T: Clone + MatrixElem> ops::Mul<T> for Matrix<T> {
+impl<T: MatrixElem> ops::MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.map_inplace(|value| *value = value.mul(&rhs));
+    }
+}
+
+impl<T: MatrixElem> ops::Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T: MatrixElem> ops::Mul<T> for &Matrix<T> {
     type Output = Matrix<T>;
 
     fn mul(self, rhs: T) -> Self::Output {
@@ -250,8 +366,62 @@ impl<T: Clone + MatrixElem> ops::Mul<T> for Matrix<T> {
     }
 }
 
-// Matrix * Matrix
-impl<T: Clone + MatrixElem> ops::Mul for Matrix<T> {
+// Matrix / Scalar
+impl<T: Field> ops::DivAssign<T> for Matrix<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.map_inplace(|value| *value = value.div(&rhs));
+    }
+}
+
+impl<T: Field> ops::Div<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn div(mut self, rhs: T) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl<T: Field> ops::Div<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.clone() / rhs
+    }
+}
+
+// -Matrix
+impl<T: Ring> ops::Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Self::Output {
+        self.map_inplace(|value| *value = value.neg());
+        self
+    }
+}
+
+impl<T: Ring> ops::Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+// Matrix * Matrix - the product can't be written into either operand, so there's nothing to reuse
+impl<T: MatrixElem> ops::MulAssign<&Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = self.mul_matrix(rhs);
+    }
+}
+
+impl<T: MatrixElem> ops::MulAssign for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self = self.mul_matrix(&rhs);
+    }
+}
+
+impl<T: MatrixElem> ops::Mul for Matrix<T> {
     type Output = Matrix<T>;
 
     fn mul(self, rhs: Self) -> Self::Output {
@@ -259,8 +429,23 @@ impl<T: Clone + MatrixElem> ops::Mul for Matrix<T> {
     }
 }
 
-// &Matrix * &Matrix
-impl<T: Clone + MatrixElem> ops::Mul for &Matrix<T> {
+impl<T: MatrixElem> ops::Mul<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.mul_matrix(rhs)
+    }
+}
+
+impl<T: MatrixElem> ops::Mul<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        self.mul_matrix(&rhs)
+    }
+}
+
+impl<T: MatrixElem> ops::Mul for &Matrix<T> {
     type Output = Matrix<T>;
 
     fn mul(self, rhs: Self) -> Self::Output {
@@ -280,14 +465,9 @@ impl<T: Clone + MatrixElem> Matrix<T> {
             for horr_index in 0..rhs.width() {
                 
                 // Initialize new cell with first multiplication to avoid requiring T::zero()
-                let mut new_val = self.rows(vert_index)[0].clone().mul(rhs.rows(0)[horr_index].clone());
+                let mut new_val = self.rows(vert_index)[0].mul(&rhs.rows(0)[horr_index]);
                 for i in 1..self.width() {    
-
-                    new_val = new_val.add(
-                        self.rows(vert_index)[i].clone()
-                        .mul(
-                        rhs.rows(i)[horr_index].clone())
-                    );
+                    new_val = new_val.add(&self.rows(vert_index)[i].mul(&rhs.rows(i)[horr_index]));
                 }
                 ret.push_to_row(vert_index, new_val);
             }
@@ -299,41 +479,113 @@ impl<T: Clone + MatrixElem> Matrix<T> {
         let mut ret: Matrix<T> = Matrix::with_capacity(self.height(), self.width());
         for vert_index in 0..self.height() {
             for horr_index in 0..self.width() {
-                ret.push_to_row(vert_index, self.rows(vert_index)[horr_index].clone().mul(rhs.clone()));
+                ret.push_to_row(vert_index, self.rows(vert_index)[horr_index].mul(rhs));
             }
         }
         ret
     }
 
+    pub fn norm(&self) -> f64 {
+        let ret: f64 = self.iter()
+            .map(|value| Float::powi(value.modulus_f64().expect("Matrix element can't be converted to f64"), 2))
+            .sum();
 
+        Float::sqrt(ret)
+    }
+}
+
+impl<T: Semiring> Matrix<T> {
+
+    // Row and column vectors can be mixed, only the number of elements has to match
     pub fn dot(&self, other: &Self) -> T {
+        if !self.is_vector() || !other.is_vector() {
+            panic!("Dot operation only allowed on vectors! A=[{},{}], B=[{},{}]", self.height(), self.width(), other.height(), other.width())
+        }
 
-        // Only allowed if both matrices have dim == 1
-        if self.height() != 1 || other.height() != 1 {
-            panic!("Dot operation only allowed on vectors! Matrix A has dim={}, Matrix B has dim={}", self.height(), other.height())
+        let (length, other_length) = (self.height() * self.width(), other.height() * other.width());
+        if length != other_length {
+            panic!("Dot operation only allowed on vectors of the same length. Vector A length={length}, Vector B length={other_length}")
         }
 
-        if self.width() != other.width() {
-            panic!("Dot operation only allowed on vectors of the same length. Vector A length={}, Vector B length={}", self.width(), other.width())
+        // Empty vectors give 0, same as Vector::dot
+        self.iter().zip(other.iter()).fold(T::zero(), |acc, (a, b)| acc.add(&a.mul(b)))
+    }
+
+    // A^exponent by repeated squaring, A^0 = I - over a semiring this is also walks of exactly that length
+    pub fn pow(&self, exponent: u32) -> Self {
+        if self.height() != self.width() {
+            panic!("Cannot raise a non-square matrix to a power! A=[{},{}]", self.height(), self.width());
         }
 
-        let mut ret = self.rows(0)[0].clone().mul(other.rows(0)[0].clone());
-        for i in 1..self.width() {
-            ret = ret.add(self.rows(0)[i].clone().mul(other.rows(0)[i].clone()));
+        let mut ret = Matrix::identity(self.height());
+        let mut base = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                ret = ret.mul_matrix(&base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul_matrix(&base);
+            }
         }
         ret
     }
-    
-    pub fn norm(&self) -> f64 {
-        let mut ret = 0.0f64;
+}
+
+impl<T: Field> Matrix<T> {
+
+    // What pivot tolerances are relative to, the magnitude of 0 for an empty matrix
+    pub(crate) fn largest_magnitude(&self) -> T::Magnitude {
+        self.rows.iter().flatten().map(T::magnitude).fold(T::zero().magnitude(), |acc, value| if value > acc { value } else { acc })
+    }
+}
+
+impl<T: ComplexField> Matrix<T> {
+
+    // Same as norm() but computed in T's real type instead of going through f64
+    pub fn frobenius_norm(&self) -> T::Real {
+        let mut ret = T::Real::zero();
         for i in 0..self.height() {
             for j in 0..self.width() {
-                ret += self.rows(i)[j].clone().to_f64().powi(2);
+                let modulus = self.rows(i)[j].modulus();
+                ret = ret.add(&modulus.mul(&modulus));
             }
         }
 
         ret.sqrt()
     }
+
+    // Conjugate transpose
+    pub fn adjoint(self) -> Self {
+        let mut ret = Matrix::with_capacity(self.width(), self.height());
+        for row_index in 0..self.height() {
+            for column_index in 0..self.width() {
+                ret.push_to_row(column_index, self.rows(row_index)[column_index].conj());
+            }
+        }
+        ret
+    }
+}
+
+impl<T: ComplexField + PartialEq> Matrix<T> {
+
+    // Equal to its own conjugate transpose, for real matrices this means symmetric
+    pub fn is_hermitian(&self) -> bool {
+        if self.height() != self.width() {
+            return false;
+        }
+
+        for row_index in 0..self.height() {
+            for col_index in row_index..self.width() {
+                if self.rows(row_index)[col_index] != self.rows(col_index)[row_index].conj() {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
 }
 
 impl<T: fmt::Display> fmt::Display for Matrix<T> {
@@ -351,7 +603,7 @@ impl<T: fmt::Display> fmt::Display for Matrix<T> {
             }
 
             if row < self.rows.len() - 1 {
-                write!(f, "\n")?
+                writeln!(f)?
             }
         }
         
@@ -359,6 +611,12 @@ impl<T: fmt::Display> fmt::Display for Matrix<T> {
     }
 }
 
+impl<T> Default for Matrix<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Matrix<T> {
     pub fn new() -> Matrix<T> {
         Matrix { rows: vec![] }
@@ -380,19 +638,164 @@ impl<T> Matrix<T> {
         self.rows[0].len()
     }
 
-    fn with_capacity(height: usize, width: usize) -> Matrix<T> {
+    // A single row or a single column
+    pub fn is_vector(&self) -> bool {
+        self.height() == 1 || self.width() == 1
+    }
+
+    // New matrix of the same size with f applied to every element
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Matrix<U> {
+        Matrix { rows: self.rows.iter().map(|row| row.iter().map(&mut f).collect()).collect() }
+    }
+
+    pub fn map_inplace(&mut self, f: impl FnMut(&mut T)) {
+        self.rows.iter_mut().flatten().for_each(f);
+    }
+
+    // f applied to pairs of elements at the same position, both matrices must have the same size
+    pub fn zip_map<U, V>(&self, other: &Matrix<U>, mut f: impl FnMut(&T, &U) -> V) -> Matrix<V> {
+        if self.height() != other.height() || self.width() != other.width() {
+            panic!("Can't zip matrices of different sizes: A=[{},{}], B=[{},{}]", self.height(), self.width(), other.height(), other.width())
+        }
+
+        Matrix {
+            rows: self.rows.iter()
+                .zip(&other.rows)
+                .map(|(row, other_row)| row.iter().zip(other_row).map(|(a, b)| f(a, b)).collect())
+                .collect()
+        }
+    }
+
+    // None when out of bounds instead of panicking
+    pub fn get_ref(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        self.rows.get(row_index)?.get(col_index)
+    }
+
+    pub fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        self.rows.get_mut(row_index)?.get_mut(col_index)
+    }
+
+    fn check_index(&self, row_index: usize, col_index: usize) {
+        if row_index >= self.height() || col_index >= self.width() {
+            panic!("Index out of bounds! [{row_index}, {col_index}] / [{}, {}]", self.height(), self.width())
+        }
+    }
+
+    pub(crate) fn with_capacity(height: usize, width: usize) -> Matrix<T> {
         let mut rows: Vec<Vec<T>> = Vec::with_capacity(height);
         for _ in 0..height {
             rows.push(Vec::with_capacity(width));
         }
-        Matrix { rows: rows }
+        Matrix { rows }
     }
 
-    fn rows(&self, index: usize) -> &Vec<T> {
+    pub(crate) fn rows(&self, index: usize) -> &Vec<T> {
         &self.rows[index]
     }
 
-    fn push_to_row(&mut self, index: usize, value: T) {
+    pub(crate) fn push_to_row(&mut self, index: usize, value: T) {
         self.rows[index].push(value);
     }
-}
\ No newline at end of file
+
+    // Callers have to keep every row the same length
+    pub(crate) fn rows_mut(&mut self) -> &mut Vec<Vec<T>> {
+        &mut self.rows
+    }
+}
+
+///
+/// Iterators - elements are always visited in row-major order
+///
+impl<T> Matrix<T> {
+    pub fn iter(&self) -> iter::Flatten<slice::Iter<'_, Vec<T>>> {
+        self.rows.iter().flatten()
+    }
+
+    pub fn iter_mut(&mut self) -> iter::Flatten<slice::IterMut<'_, Vec<T>>> {
+        self.rows.iter_mut().flatten()
+    }
+
+    pub fn row_iter(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        self.rows.iter().map(Vec::as_slice)
+    }
+
+    // Columns aren't contiguous, so each one is an iterator over its elements top to bottom
+    pub fn col_iter(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_> + ExactSizeIterator + '_ {
+        (0..self.width()).map(move |col| self.rows.iter().map(move |row| &row[col]))
+    }
+
+    // ((row, col), element)
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.rows.iter()
+            .enumerate()
+            .flat_map(|(row, values)| values.iter().enumerate().map(move |(col, value)| ((row, col), value)))
+    }
+
+    // A[0, 0], A[1, 1] ... up to the shorter dimension
+    pub fn diag_iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        (0..self.height().min(self.width())).map(move |index| &self.rows[index][index])
+    }
+
+    // Panics unless the iterator has exactly height * width elements
+    pub fn from_row_major(height: usize, width: usize, elements: impl IntoIterator<Item = T>) -> Self {
+        let mut ret = Matrix::with_capacity(height, width);
+        let mut count = 0;
+        for element in elements {
+            if count < height * width {
+                ret.push_to_row(count / width, element);
+            }
+            count += 1;
+        }
+
+        if count != height * width {
+            panic!("Expected {} elements for a [{height},{width}] matrix, got {count}", height * width);
+        }
+        ret
+    }
+}
+
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = iter::Flatten<alloc::vec::IntoIter<Vec<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.into_iter().flatten()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = iter::Flatten<slice::Iter<'a, Vec<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = iter::Flatten<slice::IterMut<'a, Vec<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// Collects rows, they all need the same length
+impl<T> FromIterator<Vec<T>> for Matrix<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(rows: I) -> Self {
+        let rows: Vec<Vec<T>> = rows.into_iter().collect();
+        if let Some(first) = rows.first() {
+            if let Some((index, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != first.len()) {
+                panic!("Rows of a matrix must have the same length! Row {index} has {} elements, expected {}", row.len(), first.len());
+            }
+        }
+        Matrix { rows }
+    }
+}
+
+impl<'a, T: Clone + 'a> FromIterator<&'a [T]> for Matrix<T> {
+    fn from_iter<I: IntoIterator<Item = &'a [T]>>(rows: I) -> Self {
+        rows.into_iter().map(<[T]>::to_vec).collect()
+    }
+}
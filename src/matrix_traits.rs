@@ -1,47 +1,71 @@
-pub trait MatrixElem {
-    fn add(self, rhs: Self) -> Self;
-    fn sub(self, rhs: Self) -> Self;
-    fn mul(self, rhs: Self) -> Self;
-    fn to_f64(self) -> f64;
+use crate::prelude::*;
+
+/*
+ * Arithmetic needed for Matrix<T> to be added and multiplied.
+ * Operands are taken by reference so that matrix products don't clone every element.
+ */
+pub trait MatrixElem: Clone {
+    fn add(&self, rhs: &Self) -> Self;
+    fn mul(&self, rhs: &Self) -> Self;
+
+    // Only needed by Matrix::norm, types without a numeric value can leave it out
+    fn to_f64(&self) -> Option<f64> {
+        None
+    }
+
+    // Distance from 0 that Matrix::norm sums up, types with more than one component (complex numbers) override it
+    fn modulus_f64(&self) -> Option<f64> {
+        self.to_f64().map(f64::abs)
+    }
+}
+
+// Subtraction is separate as semirings (booleans, min-plus) don't have it
+pub trait MatrixSub: MatrixElem {
+    fn sub(&self, rhs: &Self) -> Self;
 }
 
-pub trait MatrixZeroOne {
+pub trait MatrixZeroOne: Sized {
     fn zero() -> Self;
     fn is_zero(&self) -> bool;
     fn one() -> Self;
     fn is_one(&self) -> bool;
 }
 
+// Zero and one usable in const contexts, for types that can build them without allocating
+pub trait MatrixConstZeroOne: MatrixZeroOne {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
 ///
-/// Strings
+/// Strings - deprecated with matrix::FromStr, results aren't parenthesized so they are ambiguous. Use expr::Expr
 ///
 impl MatrixElem for String {
-    fn add(self, rhs: Self) -> Self {
+    fn add(&self, rhs: &Self) -> Self {
         format!("{}+{}", self, rhs)
     }
 
+    fn mul(&self, rhs: &Self) -> Self {
+        format!("{}{}", self, rhs)
+    }
+}
+
+impl MatrixSub for String {
+
     // A bit overengineered but fun
-    fn sub(self, rhs: Self) -> Self {
-        let s = "+".to_owned() + &rhs;
+    fn sub(&self, rhs: &Self) -> Self {
+        let s = "+".to_owned() + rhs;
         match self.find(&s) {
             Some(_) => self.replacen(&s, "", 1),
             None => {
                 let s = rhs.clone() + "+";
                 match self.find(&s) {
                     Some(_) => self.replacen(&s, "", 1),
-                    None => return format!("{}-{}", self, rhs)
+                    None => format!("{}-{}", self, rhs)
                 }
             }
         }
     }
-
-    fn mul(self, rhs: Self) -> Self {
-        format!("{}{}", self, rhs)
-    }
-
-    fn to_f64(self) -> f64 {
-        panic!("Cannot convert string to f64")
-    }
 }
 
 ///
@@ -50,42 +74,49 @@ impl MatrixElem for String {
 macro_rules! impl_matrix_traits {
     ($zero:expr, $one:expr, $($type:ty),* $(,)*) => {$(
         impl MatrixElem for $type {
-            fn add(self, rhs: Self) -> Self {
+            fn add(&self, rhs: &Self) -> Self {
                 self + rhs
             }
 
-            fn sub(self, rhs: Self) -> Self {
-                self - rhs
+            fn mul(&self, rhs: &Self) -> Self {
+                self * rhs
             }
 
-            fn mul(self, rhs: Self) -> Self {
-                self * rhs
+            fn to_f64(&self) -> Option<f64> {
+                Some(*self as f64)
             }
+        }
 
-            fn to_f64(self) -> f64 {
-                self as f64
+        impl MatrixSub for $type {
+            fn sub(&self, rhs: &Self) -> Self {
+                self - rhs
             }
         }
 
         impl MatrixZeroOne for $type {
             fn zero() -> $type {
-                $zero
+                Self::ZERO
             }
 
             fn one() -> $type {
-                $one
+                Self::ONE
             }
 
             fn is_zero(&self) -> bool {
-                &$zero == self
+                &Self::ZERO == self
             }
 
             fn is_one(&self) -> bool {
-                &$one == self
+                &Self::ONE == self
             }
         }
+
+        impl MatrixConstZeroOne for $type {
+            const ZERO: $type = $zero;
+            const ONE: $type = $one;
+        }
     )*};
 }
 
-impl_matrix_traits!(0, 1, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_matrix_traits!(0, 1, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 impl_matrix_traits!(0., 1., f32, f64);
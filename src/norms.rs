@@ -0,0 +1,224 @@
+use crate::{float::Float, lu_decomposition::*, matrix::*, matrix_traits::*, prelude::*, scalar::*, triangular_solve::*};
+
+// Matrix norms, Two and Nuclear go through the singular values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Norm {
+
+    // Largest column sum of |a_ij|
+    One,
+
+    // Largest singular value
+    Two,
+
+    // Largest row sum of |a_ij|
+    Inf,
+
+    // Largest |a_ij|, not submultiplicative
+    Max,
+    Frobenius,
+
+    // Sum of singular values
+    Nuclear
+}
+
+impl<T: RealField> Matrix<T> {
+
+    /*
+     * One-sided Jacobi - columns are rotated in pairs until they are all orthogonal,
+     * their lengths are then the singular values. Sorted from the largest.
+     */
+    pub fn singular_values(&self) -> Vec<T> {
+
+        // Columns of a wide matrix aren't independent, its transpose has the same singular values
+        let mut u = if self.height() < self.width() { self.clone().transpose() } else { self.clone() };
+        let (height, width) = (u.height(), u.width());
+        let column_dot = |u: &Matrix<T>, i: usize, j: usize| {
+            (0..height).fold(T::zero(), |acc, row| acc.add(&u[(row, i)].mul(&u[(row, j)])))
+        };
+
+        let two = T::one().add(&T::one());
+        for _ in 0..64 {
+            let mut rotated = false;
+            for i in 0..width {
+                for j in (i + 1)..width {
+                    let alpha = column_dot(&u, i, i);
+                    let beta = column_dot(&u, j, j);
+                    let gamma = column_dot(&u, i, j);
+                    if gamma.abs() <= T::epsilon().mul(&alpha.mul(&beta).sqrt()) {
+                        continue;
+                    }
+                    rotated = true;
+
+                    // Rotation that zeroes the dot product of the two columns
+                    let zeta = beta.sub(&alpha).div(&two.mul(&gamma));
+                    let sign = if zeta < T::zero() { T::one().neg() } else { T::one() };
+                    let t = sign.div(&zeta.abs().add(&T::one().add(&zeta.mul(&zeta)).sqrt()));
+                    let c = T::one().add(&t.mul(&t)).sqrt().recip();
+                    let s = c.mul(&t);
+                    for row in 0..height {
+                        let (x, y) = (u[(row, i)].clone(), u[(row, j)].clone());
+                        u[(row, i)] = c.mul(&x).sub(&s.mul(&y));
+                        u[(row, j)] = s.mul(&x).add(&c.mul(&y));
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut ret: Vec<T> = (0..width).map(|col| column_dot(&u, col, col).sqrt()).collect();
+        ret.sort_by(|a, b| b.partial_cmp(a).unwrap_or(core::cmp::Ordering::Equal));
+        ret
+    }
+
+    pub fn matrix_norm(&self, norm: Norm) -> T {
+        let largest = |values: &mut dyn Iterator<Item = T>| values.fold(T::zero(), |acc, value| if value > acc { value } else { acc });
+        match norm {
+            Norm::One => largest(&mut self.col_iter().map(|col| col.fold(T::zero(), |acc, value| acc.add(&value.abs())))),
+            Norm::Inf => largest(&mut self.row_iter().map(|row| row.iter().fold(T::zero(), |acc, value| acc.add(&value.abs())))),
+            Norm::Max => largest(&mut self.iter().map(T::abs)),
+            Norm::Frobenius => self.iter().fold(T::zero(), |acc, value| acc.add(&value.mul(value))).sqrt(),
+            Norm::Two => self.singular_values().into_iter().next().unwrap_or(T::zero()),
+            Norm::Nuclear => self.singular_values().iter().fold(T::zero(), |acc, value| acc.add(value))
+        }
+    }
+}
+
+impl<T: MatrixElem> Matrix<T> {
+
+    // (sum |x_i|^p)^(1/p) of a row or column vector, through f64 like norm(). p can be f64::INFINITY
+    pub fn p_norm(&self, p: f64) -> f64 {
+        if self.height() > 1 && self.width() > 1 {
+            panic!("p-norm is only defined for vectors! A=[{},{}]", self.height(), self.width());
+        }
+
+        if p < 1. {
+            panic!("p-norm needs p >= 1, got {p}");
+        }
+
+        let values = self.iter().map(|value| value.to_f64().expect("Matrix element can't be converted to f64").abs());
+        if p == f64::INFINITY {
+            return values.fold(0., f64::max);
+        }
+
+        Float::powf(values.map(|value| Float::powf(value, p)).sum::<f64>(), 1. / p)
+    }
+}
+
+/*
+ * Condition number ||A|| ||A^-1|| in the given norm, infinite for a singular A.
+ * Computes the inverse (or all singular values for the 2-norm), see cond_estimate for a cheap 1-norm bound.
+ */
+pub fn cond<T: RealField>(a: &Matrix<T>, norm: Norm) -> T {
+    if a.height() != a.width() {
+        panic!("Cannot compute condition number of a non-square matrix! A=[{},{}]", a.height(), a.width());
+    }
+
+    let infinity = T::from_f64(f64::INFINITY);
+    if norm == Norm::Two {
+        let singular_values = a.singular_values();
+        return match singular_values.last() {
+            Some(smallest) if *smallest > T::zero() => singular_values[0].div(smallest),
+            Some(_) => infinity,
+            None => T::zero()
+        };
+    }
+
+    let lu = lu_gauss(a);
+    if is_singular(&lu) {
+        return infinity;
+    }
+
+    let inverse = lu_solve(&lu, &Matrix::identity(a.height()));
+    a.matrix_norm(norm).mul(&inverse.matrix_norm(norm))
+}
+
+// lu_gauss leaves an exact 0 on the diagonal of U for every column it couldn't pivot in
+fn is_singular<T: RealField>(lu: &LUResult<T>) -> bool {
+    (0..lu.p.size()).any(|index| lu.u.get(index, index).is_zero())
+}
+
+/*
+ * Hager's estimate of ||A^-1||_1 with Higham's refinements (as in LAPACK xLACON), from the LU of A.
+ * Each step solves with A and A^T once, O(n^2), and it usually stops after 2-3 steps. The result
+ * is a lower bound that is rarely more than a small factor off.
+ */
+pub fn inverse_norm_1_estimate<T: RealField>(lu: &LUResult<T>) -> T {
+    let size = lu.p.size();
+    if size == 0 {
+        return T::zero();
+    }
+
+    if is_singular(lu) {
+        return T::from_f64(f64::INFINITY);
+    }
+
+    let n = T::from_f64(size as f64);
+    let norm_1 = |x: &Matrix<T>| x.iter().fold(T::zero(), |acc, value| acc.add(&value.abs()));
+
+    // Start from the average of the unit vectors
+    let mut x = Matrix::new_fill(size, 1, n.recip());
+    let mut estimate = T::zero();
+    let mut previous_index = None;
+    for _ in 0..5 {
+        let y = lu_solve(lu, &x);
+
+        // Like LAPACK, stop as soon as a step doesn't improve the estimate and keep the better one
+        let new_estimate = norm_1(&y);
+        if new_estimate <= estimate {
+            break;
+        }
+        estimate = new_estimate;
+
+        // Gradient of ||A^-1 x||_1 at x is A^-T sign(y)
+        let signs = y.map(|value| if *value < T::zero() { T::one().neg() } else { T::one() });
+        let z = solve_transposed(lu, &signs);
+
+        let (index, largest) = z.iter().enumerate().fold((0, T::zero()), |best, (index, value)| {
+            if value.abs() > best.1 { (index, value.abs()) } else { best }
+        });
+        let slope = z.iter().zip(x.iter()).fold(T::zero(), |acc, (a, b)| acc.add(&a.mul(b)));
+
+        // No unit vector increases the estimate, or the same one would be picked again
+        if largest <= slope || previous_index == Some(index) {
+            break;
+        }
+
+        x = Matrix::new_fill(size, 1, T::zero());
+        x[(index, 0)] = T::one();
+        previous_index = Some(index);
+    }
+
+    // Higham's extra vector with alternating signs catches cases where the gradient steps get stuck
+    if size > 1 {
+        let alternating = Matrix::from_row_major(size, 1, (0..size).map(|i| {
+            let value = T::one().add(&T::from_f64(i as f64).div(&T::from_f64((size - 1) as f64)));
+            if i % 2 == 0 { value } else { value.neg() }
+        }));
+        let three = T::from_f64(3.);
+        let alternative = T::from_f64(2.).mul(&norm_1(&lu_solve(lu, &alternating))).div(&three.mul(&n));
+        if alternative > estimate {
+            estimate = alternative;
+        }
+    }
+
+    estimate
+}
+
+// Solves A^T x = b from PA = LU: A^T = U^T L^T P, so U^T w = b, L^T v = w and x = P^T v
+fn solve_transposed<T: RealField>(lu: &LUResult<T>, b: &Matrix<T>) -> Matrix<T> {
+    let w = solve_upper_triangular(&lu.u, b, TriangularSolveOptions { transpose: true, ..Default::default() });
+    let v = solve_lower_triangular(&lu.l, &w, TriangularSolveOptions { unit_diagonal: true, transpose: true });
+    lu.p.solve(&v)
+}
+
+// Estimate of the 1-norm condition number reusing the LU of A - cheap enough to run before every solve
+pub fn cond_estimate<T: RealField>(a: &Matrix<T>, lu: &LUResult<T>) -> T {
+    if a.height() != a.width() || a.height() != lu.p.size() {
+        panic!("LU doesn't belong to A! A=[{},{}], LU size={}", a.height(), a.width(), lu.p.size());
+    }
+
+    a.matrix_norm(Norm::One).mul(&inverse_norm_1_estimate(lu))
+}
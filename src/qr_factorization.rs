@@ -1,4 +1,4 @@
-use crate::matrix::*;
+use crate::{matrix::*, matrix_traits::*, prelude::*, scalar::*, triangular_solve::*};
 
 pub struct QRResult<T> {
     pub r: Matrix<T>,
@@ -8,25 +8,29 @@ pub struct QRResult<T> {
 /*
  * Computes householder reflector of the full first column of given matrix
  */
-pub fn householder(a: &Matrix<f64>) -> Matrix<f64> {
+pub fn householder<T: ComplexField>(a: &Matrix<T>) -> Matrix<T> {
 
     // TODO: replace with view
     let y = a.cut(.., ..1);
 
     // Instead of creating a e = (1, 0, ... 0) vector only care about first value
+    // For real numbers the phase is just the sign of y1, for complex it's y1/|y1|
     let y1 = y.get(0,0);
-    let y1_sign = if y1 >= 0. { 1. } else { -1. };
+    let y1_modulus = y1.modulus();
+    let y1_phase = if y1_modulus.is_zero() { T::one() } else { y1.div(&T::from_real(y1_modulus)) };
     let mut w = y;
 
-    // w = y + sign(y1)||y||e - only the first value is modified
-    w.set(0, 0, y1 + y1_sign * w.norm());
+    // w = y + phase(y1)||y||e - only the first value is modified
+    w[(0, 0)] = y1.add(&y1_phase.mul(&T::from_real(w.frobenius_norm())));
 
-    let v = (1./w.norm()) * w;
-    let vvt2 = v.clone() * v.transpose() * 2.;
+    // H = I - 2vv^H, v^H is the same as v^T for real numbers
+    let v = &w / T::from_real(w.frobenius_norm());
+    let two = T::one().add(&T::one());
+    let vvt2 = v.clone() * v.adjoint() * two;
     Matrix::identity(vvt2.height()) - vvt2
 }
 
-pub fn qr(a: &Matrix<f64>) -> QRResult<f64> {
+pub fn qr<T: ComplexField>(a: &Matrix<T>) -> QRResult<T> {
     // Make a copy of A
     let mut a = a.clone();
 
@@ -37,7 +41,7 @@ pub fn qr(a: &Matrix<f64>) -> QRResult<f64> {
     let h_size = a.height();
 
     // Resulting reflectors
-    let mut hs: Vec<Matrix<f64>> = vec![];
+    let mut hs: Vec<Matrix<T>> = vec![];
 
     for col_index in 0..h_count {
 
@@ -48,7 +52,7 @@ pub fn qr(a: &Matrix<f64>) -> QRResult<f64> {
         let h = householder(&cut);
 
         // Save the reflector to return
-        let mut full_reflector: Matrix<f64> = Matrix::identity(h_size);
+        let mut full_reflector: Matrix<T> = Matrix::identity(h_size);
         full_reflector.set_matrix(&h, col_index, col_index);
         hs.push(full_reflector);
 
@@ -62,15 +66,39 @@ pub fn qr(a: &Matrix<f64>) -> QRResult<f64> {
     QRResult { r: a, h: hs }
 }
 
-pub fn q_from_reflectors(hs: &Vec<Matrix<f64>>) -> Matrix<f64>
+pub fn q_from_reflectors<T: ComplexField>(hs: &[Matrix<T>]) -> Matrix<T>
 {
     if hs.is_empty() {
         panic!("Can't compute Q from a vector of H reflectors, vector is empty")
     }
 
     let mut res = hs[0].clone();
-    for index in 1..hs.len() {
-        res = &res * &hs[index];
+    for h in &hs[1..] {
+        res = &res * h;
     }
     res
-}
\ No newline at end of file
+}
+
+/*
+ * Least squares solution of Ax = b using the QR factorization of A, A can't be wider than tall
+ */
+pub fn qr_least_squares<T: ComplexField>(qr: &QRResult<T>, b: &Matrix<T>) -> Matrix<T> {
+    let width = qr.r.width();
+    if qr.r.height() < width {
+        panic!("Least squares needs A at least as tall as wide, A=[{},{}]", qr.r.height(), width);
+    }
+
+    // No unknowns to solve for
+    if width == 0 {
+        return Matrix::new();
+    }
+
+    // Q^H = H_n * ... * H_1 (every H is Hermitian), so H_1 is applied to b first
+    let mut qtb = b.clone();
+    for h in &qr.h {
+        qtb = h * &qtb;
+    }
+
+    // Rows of R below its width are 0 so only the top square part matters
+    solve_upper_triangular(&qr.r.cut(..width, ..), &qtb.cut(..width, ..), TriangularSolveOptions::default())
+}
@@ -0,0 +1,224 @@
+use crate::{matrix_traits::*, scalar::*};
+use core::{cmp::Ordering, fmt, ops};
+
+/*
+ * Exact fraction numer/denom, always kept in lowest terms with a positive denominator.
+ * Elimination over it needs no tolerance - a pivot is either exactly 0 or it isn't.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T
+}
+
+pub type Rational = Ratio<i64>;
+
+impl<T: Integer> Ratio<T> {
+    pub fn new(numer: T, denom: T) -> Self {
+        if denom.is_zero() {
+            panic!("Denominator of a fraction can't be 0");
+        }
+
+        Ratio::reduce(numer, denom)
+    }
+
+    pub fn from_integer(value: T) -> Self {
+        Ratio { numer: value, denom: T::one() }
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom.is_one()
+    }
+
+    pub fn abs(&self) -> Self {
+        Ratio { numer: self.numer.abs(), denom: self.denom.clone() }
+    }
+
+    // Largest integer <= self, the denominator is positive so only negative remainders need fixing
+    pub fn floor(&self) -> T {
+        let (q, r) = self.numer.div_rem(&self.denom);
+        if r < T::zero() { q.sub(&T::one()) } else { q }
+    }
+
+    // Nearest integer, halves are rounded up
+    pub fn round(&self) -> T {
+        self.add(&Ratio::new(T::one(), T::one().add(&T::one()))).floor()
+    }
+
+    fn reduce(numer: T, denom: T) -> Self {
+        let gcd = numer.gcd(&denom);
+        let (mut numer, mut denom) = (numer.div_rem(&gcd).0, denom.div_rem(&gcd).0);
+        if denom < T::zero() {
+            numer = numer.neg();
+            denom = denom.neg();
+        }
+        Ratio { numer, denom }
+    }
+}
+
+impl<T: Integer> From<T> for Ratio<T> {
+    fn from(value: T) -> Self {
+        Ratio::from_integer(value)
+    }
+}
+
+///
+/// Element traits
+///
+impl<T: Integer> MatrixElem for Ratio<T> {
+
+    // Going through the gcd of denominators keeps intermediate values small
+    fn add(&self, rhs: &Self) -> Self {
+        let gcd = self.denom.gcd(&rhs.denom);
+        let lhs_factor = rhs.denom.div_rem(&gcd).0;
+        let rhs_factor = self.denom.div_rem(&gcd).0;
+        Ratio::reduce(
+            self.numer.mul(&lhs_factor).add(&rhs.numer.mul(&rhs_factor)),
+            self.denom.mul(&lhs_factor))
+    }
+
+    // Cross-cancelling before multiplying keeps intermediate values small
+    fn mul(&self, rhs: &Self) -> Self {
+        let gcd_1 = self.numer.gcd(&rhs.denom);
+        let gcd_2 = rhs.numer.gcd(&self.denom);
+        Ratio::reduce(
+            self.numer.div_rem(&gcd_1).0.mul(&rhs.numer.div_rem(&gcd_2).0),
+            self.denom.div_rem(&gcd_2).0.mul(&rhs.denom.div_rem(&gcd_1).0))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.numer.to_f64()? / self.denom.to_f64()?)
+    }
+}
+
+impl<T: Integer> MatrixSub for Ratio<T> {
+    fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+}
+
+impl<T: Integer> MatrixZeroOne for Ratio<T> {
+    fn zero() -> Self {
+        Ratio::from_integer(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+
+    fn one() -> Self {
+        Ratio::from_integer(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.numer.is_one() && self.denom.is_one()
+    }
+}
+
+impl<T: Integer + MatrixConstZeroOne> MatrixConstZeroOne for Ratio<T> {
+    const ZERO: Self = Ratio { numer: T::ZERO, denom: T::ONE };
+    const ONE: Self = Ratio { numer: T::ONE, denom: T::ONE };
+}
+
+impl<T: Integer> Ring for Ratio<T> {
+    fn neg(&self) -> Self {
+        Ratio { numer: self.numer.neg(), denom: self.denom.clone() }
+    }
+}
+
+impl<T: Integer> Field for Ratio<T> {
+    type Magnitude = Ratio<T>;
+
+    fn div(&self, rhs: &Self) -> Self {
+        if rhs.is_zero() {
+            panic!("Division of a fraction by 0");
+        }
+
+        self.mul(&Ratio::reduce(rhs.denom.clone(), rhs.numer.clone()))
+    }
+
+    fn magnitude(&self) -> Self::Magnitude {
+        self.abs()
+    }
+
+    // Exact arithmetic, only a true 0 is skipped
+    fn negligible(_: &Self::Magnitude) -> Self::Magnitude {
+        Ratio::zero()
+    }
+}
+
+impl<T: Integer> PartialOrd for Ratio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Denominators are positive so a/b < c/d is the same as ad < cb
+impl<T: Integer> Ord for Ratio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.numer.mul(&other.denom).cmp(&other.numer.mul(&self.denom))
+    }
+}
+
+///
+/// Operators
+///
+impl<T: Integer> ops::Add for Ratio<T> {
+    type Output = Ratio<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MatrixElem::add(&self, &rhs)
+    }
+}
+
+impl<T: Integer> ops::Sub for Ratio<T> {
+    type Output = Ratio<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        MatrixSub::sub(&self, &rhs)
+    }
+}
+
+impl<T: Integer> ops::Mul for Ratio<T> {
+    type Output = Ratio<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        MatrixElem::mul(&self, &rhs)
+    }
+}
+
+impl<T: Integer> ops::Div for Ratio<T> {
+    type Output = Ratio<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Field::div(&self, &rhs)
+    }
+}
+
+impl<T: Integer> ops::Neg for Ratio<T> {
+    type Output = Ratio<T>;
+
+    fn neg(self) -> Self::Output {
+        Ring::neg(&self)
+    }
+}
+
+// Prints as numer/denom, or just numer for integers
+impl<T: Integer + fmt::Display> fmt::Display for Ratio<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        }
+        else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
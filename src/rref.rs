@@ -1,40 +1,43 @@
-use crate::matrix::*;
+use crate::{matrix::*, prelude::*, scalar::*};
 
-const EPS: f64 = 1e-12;
-
-pub fn rref(a: &Matrix<f64>) -> Matrix<f64> {
+pub fn rref<T: Field>(a: &Matrix<T>) -> Matrix<T> {
     
     // Setup R - same size as A, is our working matrix
-    let mut r: Matrix<f64> = a.clone();
+    let mut r: Matrix<T> = a.clone();
 
     // Each row is the basis for Gaussian Elimination
+    let negligible = T::negligible(&a.largest_magnitude());
     let mut row = 0;
     for col in 0..r.width() {
 
         // Find the row to pivot to the top - with highest element in the column
-        let mut biggest = (0, 0.);
+        let mut biggest: Option<(usize, T::Magnitude)> = None;
         for pivot_row in row..r.height() {
-            let val = &r.get(pivot_row, col);
-            if val.abs() > biggest.1 {
-                biggest = (pivot_row, val.abs());
+            let val = r[(pivot_row, col)].magnitude();
+            if val > negligible && biggest.as_ref().is_none_or(|b| val > b.1) {
+                biggest = Some((pivot_row, val));
             }
         }
 
-        // If there are only "0s" in column, skip it - col is incremented but row is not
-        if biggest.1 <= EPS {
+        // If there are only "0s" in column, skip it - col is incremented but row is not.
+        // Rounding noise left in the column is flushed so that it isn't taken for a pivot later
+        let Some((pivot_row, _)) = biggest else {
+            for row_index in row..r.height() {
+                r[(row_index, col)] = r[(row_index, col)].flush_negligible();
+            }
             continue;
-        }
+        };
 
         // Swap two rows
-        r.swap_rows(row, biggest.0);
+        r.swap_rows(row, pivot_row);
 
         // Grab the first number in the row 
-        let pivot = r.get(row, col);
+        let pivot = r[(row, col)].clone();
 
-        // If pivot is not close to 1, divide whole row by it
-        if f64::abs(pivot - 1.) > EPS {
-            for col_index in col..r.width() {
-                r.set(row, col_index, r.get(row, col_index) / pivot);
+        // If pivot is not 1, divide whole row by it
+        if !pivot.is_one() {
+            for value in &mut r[row][col..] {
+                *value = value.div(&pivot);
             }
         }
 
@@ -45,11 +48,11 @@ pub fn rref(a: &Matrix<f64>) -> Matrix<f64> {
             }
 
             // Quotient between pivot (which is equal 1) and the number in the current column in other row
-            let quotient = r.get(row_index, col);
+            let quotient = r[(row_index, col)].clone();
 
             // Substract the whole row to the right of current column
             for col_index in col..r.width() {
-                r.set(row_index, col_index, r.get(row_index, col_index) - quotient * r.get(row, col_index));
+                r[(row_index, col_index)] = r[(row_index, col_index)].sub(&quotient.mul(&r[(row, col_index)]));
             }
         }
 
@@ -59,15 +62,21 @@ pub fn rref(a: &Matrix<f64>) -> Matrix<f64> {
     r
 }
 
-pub fn rank(a: &Matrix<f64>) -> usize {
+pub fn rank<T: Field>(a: &Matrix<T>) -> usize {
 
     let reduced = rref(a);
+    let negligible = T::negligible(&reduced.largest_magnitude());
     let mut rank = 0;
 
     for col in 0..reduced.width() {
 
+        // Every row already has its pivot
+        if rank == reduced.height() {
+            break;
+        }
+
         // If we stumble upon 0, don't increase rank
-        if reduced.get(rank, col) < EPS {
+        if reduced[(rank, col)].magnitude() <= negligible {
             continue;
         }
 
@@ -75,4 +84,69 @@ pub fn rank(a: &Matrix<f64>) -> usize {
     }
 
     rank
-}
\ No newline at end of file
+}
+
+// Columns holding the leading 1 of each row of a matrix in RREF
+fn pivot_columns<T: Field>(reduced: &Matrix<T>) -> Vec<usize> {
+    let negligible = T::negligible(&reduced.largest_magnitude());
+    let mut pivots = vec![];
+    for col in 0..reduced.width() {
+        if pivots.len() == reduced.height() {
+            break;
+        }
+
+        if reduced[(pivots.len(), col)].magnitude() > negligible {
+            pivots.push(col);
+        }
+    }
+    pivots
+}
+
+/*
+ * Basis of {x : Ax = 0}, one vector per column - a free variable set to 1 with the pivots solved for.
+ * A with full column rank gives a matrix with no columns.
+ */
+pub fn null_space<T: Field>(a: &Matrix<T>) -> Matrix<T> {
+    let reduced = rref(a);
+    let pivots = pivot_columns(&reduced);
+    let free: Vec<usize> = (0..a.width()).filter(|col| !pivots.contains(col)).collect();
+
+    let mut ret = Matrix::new_fill(a.width(), free.len(), T::zero());
+    for (index, &free_col) in free.iter().enumerate() {
+        ret[(free_col, index)] = T::one();
+        for (row, &pivot_col) in pivots.iter().enumerate() {
+            ret[(pivot_col, index)] = reduced[(row, free_col)].neg();
+        }
+    }
+    ret
+}
+
+/*
+ * One solution of Ax = b for any shape of A, free variables are set to 0.
+ * None if the system is inconsistent, every other solution differs by a vector from null_space(A).
+ */
+pub fn solve<T: Field>(a: &Matrix<T>, b: &Matrix<T>) -> Option<Matrix<T>> {
+    if b.height() != a.height() {
+        panic!("b must have the same height as A, A=[{},{}], b=[{},{}]", a.height(), a.width(), b.height(), b.width());
+    }
+
+    // Reduce [A | b] together
+    let mut augmented = Matrix::new_fill(a.height(), a.width() + b.width(), T::zero());
+    augmented.set_matrix(a, 0, 0);
+    augmented.set_matrix(b, 0, a.width());
+    let reduced = rref(&augmented);
+
+    // A pivot in the b part means a row 0 = c with c != 0
+    let pivots = pivot_columns(&reduced);
+    if pivots.last().is_some_and(|&col| col >= a.width()) {
+        return None;
+    }
+
+    let mut ret = Matrix::new_fill(a.width(), b.width(), T::zero());
+    for (row, &pivot_col) in pivots.iter().enumerate() {
+        for col in 0..b.width() {
+            ret[(pivot_col, col)] = reduced[(row, a.width() + col)].clone();
+        }
+    }
+    Some(ret)
+}
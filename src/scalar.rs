@@ -0,0 +1,163 @@
+use crate::{float::Float, matrix_traits::*};
+
+/*
+ * Elements with +, * and both identities - zero is the identity of + and annihilates under *.
+ * Enough for matrix products and powers, implemented for everything with MatrixElem and MatrixZeroOne.
+ */
+pub trait Semiring: MatrixElem + MatrixZeroOne {}
+
+impl<T: MatrixElem + MatrixZeroOne> Semiring for T {}
+
+// Semiring with -
+pub trait Ring: Semiring + MatrixSub {
+    fn neg(&self) -> Self {
+        Self::zero().sub(self)
+    }
+}
+
+// Ring with division - enough for Gaussian elimination based algorithms
+pub trait Field: Ring {
+
+    // What pivots are compared by, the bigger the better
+    type Magnitude: PartialOrd;
+
+    fn div(&self, rhs: &Self) -> Self;
+
+    fn magnitude(&self) -> Self::Magnitude;
+
+    // Anything with magnitude at or below this is treated as 0 when pivoting, scale is the largest magnitude in the matrix
+    fn negligible(scale: &Self::Magnitude) -> Self::Magnitude;
+
+    // What an entry at or below negligible() becomes once its column is skipped. Kept as it is by default,
+    // a negligible magnitude doesn't always mean 0 - an interval around 0 or a dual with a derivative part
+    fn flush_negligible(&self) -> Self {
+        self.clone()
+    }
+
+    fn recip(&self) -> Self {
+        Self::one().div(self)
+    }
+}
+
+// Ordered field with square roots - needed by orthogonal decompositions
+pub trait RealField: Field + PartialOrd {
+    fn abs(&self) -> Self;
+    fn sqrt(&self) -> Self;
+    fn epsilon() -> Self;
+    fn from_f64(value: f64) -> Self;
+}
+
+// Fields with a conjugate and a real valued modulus - real fields are their own conjugate
+pub trait ComplexField: Field {
+    type Real: RealField;
+
+    fn conj(&self) -> Self;
+    fn modulus(&self) -> Self::Real;
+    fn from_real(value: Self::Real) -> Self;
+}
+
+// Ordered ring with division with remainder - what exact rationals are built from
+pub trait Integer: Ring + Ord {
+
+    // Quotient rounded towards 0 and remainder with the sign of self, same as / and % on primitives
+    fn div_rem(&self, rhs: &Self) -> (Self, Self);
+
+    fn abs(&self) -> Self {
+        if *self < Self::zero() { self.neg() } else { self.clone() }
+    }
+
+    // Always non-negative, gcd(0, 0) = 0
+    fn gcd(&self, rhs: &Self) -> Self {
+        let mut a = self.abs();
+        let mut b = rhs.abs();
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a
+    }
+}
+
+macro_rules! impl_integer {
+    ($($type:ty),* $(,)*) => {$(
+        impl Ring for $type {}
+
+        impl Integer for $type {
+            fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+                (self / rhs, self % rhs)
+            }
+        }
+    )*};
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_real_field {
+    ($($type:ident),* $(,)*) => {$(
+        impl Ring for $type {
+            fn neg(&self) -> Self {
+                -self
+            }
+        }
+
+        impl Field for $type {
+            type Magnitude = $type;
+
+            fn div(&self, rhs: &Self) -> Self {
+                self / rhs
+            }
+
+            fn magnitude(&self) -> Self::Magnitude {
+                $type::abs(*self)
+            }
+
+            // Relative to the matrix so that small-scale matrices aren't treated as 0, leaves room
+            // for rounding errors accumulated during elimination (~1e-12 of the largest entry for f64)
+            fn negligible(scale: &Self::Magnitude) -> Self::Magnitude {
+                scale * $type::EPSILON * 4096.
+            }
+
+            // Only rounding noise is that small, so it's a true 0
+            fn flush_negligible(&self) -> Self {
+                0.
+            }
+        }
+
+        impl RealField for $type {
+            fn abs(&self) -> Self {
+                $type::abs(*self)
+            }
+
+            fn sqrt(&self) -> Self {
+                Float::sqrt(*self)
+            }
+
+            fn epsilon() -> Self {
+                $type::EPSILON
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $type
+            }
+        }
+
+        impl ComplexField for $type {
+            type Real = $type;
+
+            fn conj(&self) -> Self {
+                *self
+            }
+
+            fn modulus(&self) -> Self::Real {
+                $type::abs(*self)
+            }
+
+            fn from_real(value: Self::Real) -> Self {
+                value
+            }
+        }
+    )*};
+}
+
+impl_real_field!(f32, f64);
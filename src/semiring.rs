@@ -0,0 +1,178 @@
+use crate::{matrix::*, matrix_traits::*, scalar::*};
+use core::fmt;
+
+/*
+ * Or/and semiring - matrix products answer "is there a walk", the closure is reachability
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Boolean(pub bool);
+
+impl From<bool> for Boolean {
+    fn from(value: bool) -> Self {
+        Boolean(value)
+    }
+}
+
+impl MatrixElem for Boolean {
+    fn add(&self, rhs: &Self) -> Self {
+        Boolean(self.0 || rhs.0)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        Boolean(self.0 && rhs.0)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(if self.0 { 1. } else { 0. })
+    }
+}
+
+impl MatrixZeroOne for Boolean {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.0
+    }
+
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        self.0
+    }
+}
+
+impl MatrixConstZeroOne for Boolean {
+    const ZERO: Self = Boolean(false);
+    const ONE: Self = Boolean(true);
+}
+
+impl fmt::Display for Boolean {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.0 { 1 } else { 0 })
+    }
+}
+
+/*
+ * Tropical semirings - "addition" picks the better value, "multiplication" adds.
+ * None is the infinity that is never picked, which is also the zero of the semiring.
+ */
+macro_rules! tropical_impl {
+    ($name:ident, $better:tt, $infinity:expr) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $name<T>(Option<T>);
+
+        impl<T> $name<T> {
+            pub const fn new(value: T) -> Self {
+                $name(Some(value))
+            }
+
+            pub const fn infinity() -> Self {
+                $name(None)
+            }
+
+            pub fn value(&self) -> Option<&T> {
+                self.0.as_ref()
+            }
+
+            pub fn is_infinite(&self) -> bool {
+                self.0.is_none()
+            }
+        }
+
+        impl<T> From<T> for $name<T> {
+            fn from(value: T) -> Self {
+                $name::new(value)
+            }
+        }
+
+        impl<T: Semiring + PartialOrd> MatrixElem for $name<T> {
+            fn add(&self, rhs: &Self) -> Self {
+                match (&self.0, &rhs.0) {
+                    (Some(a), Some(b)) => $name(Some(if b $better a { b.clone() } else { a.clone() })),
+                    (Some(_), None) => self.clone(),
+                    (None, _) => rhs.clone()
+                }
+            }
+
+            fn mul(&self, rhs: &Self) -> Self {
+                match (&self.0, &rhs.0) {
+                    (Some(a), Some(b)) => $name(Some(a.add(b))),
+                    _ => $name(None)
+                }
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                match &self.0 {
+                    Some(value) => value.to_f64(),
+                    None => Some($infinity)
+                }
+            }
+        }
+
+        impl<T: Semiring + PartialOrd> MatrixZeroOne for $name<T> {
+            fn zero() -> Self {
+                $name(None)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0.is_none()
+            }
+
+            fn one() -> Self {
+                $name(Some(T::zero()))
+            }
+
+            fn is_one(&self) -> bool {
+                self.0.as_ref().is_some_and(|value| value.is_zero())
+            }
+        }
+
+        impl<T: Semiring + PartialOrd + MatrixConstZeroOne> MatrixConstZeroOne for $name<T> {
+            const ZERO: Self = $name(None);
+            const ONE: Self = $name(Some(T::ZERO));
+        }
+
+        // Infinity prints as inf/-inf, precision is passed through to the value
+        impl<T: fmt::Display> fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match (&self.0, f.precision()) {
+                    (Some(value), Some(precision)) => write!(f, "{:.*}", precision, value),
+                    (Some(value), None) => write!(f, "{}", value),
+                    (None, _) => write!(f, "{}", $infinity)
+                }
+            }
+        }
+    };
+}
+
+// min/+ - matrix powers give shortest walks, the closure gives all-pairs shortest paths
+tropical_impl!(MinPlus, <, f64::INFINITY);
+
+// max/+ - matrix powers give longest walks, used for critical paths in schedules
+tropical_impl!(MaxPlus, >, f64::NEG_INFINITY);
+
+impl<T: Semiring> Matrix<T> {
+
+    /*
+     * Kleene star I + A + A^2 + ..., computed as (I + A)^(n-1) by repeated squaring.
+     * Only valid when addition is idempotent and there are no improving cycles -
+     * reachability for Boolean, shortest paths for MinPlus without negative cycles.
+     */
+    pub fn closure(&self) -> Self {
+        if self.height() != self.width() {
+            panic!("Cannot compute closure of a non-square matrix! A=[{},{}]", self.height(), self.width());
+        }
+
+        let mut ret = self + Matrix::identity(self.height());
+        let mut length = 1;
+        while length + 1 < self.height() {
+            ret = &ret * &ret;
+            length *= 2;
+        }
+        ret
+    }
+}
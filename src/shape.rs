@@ -0,0 +1,170 @@
+use crate::{matrix::*, prelude::*, statistics::Axis};
+use core::iter;
+
+/*
+ * Side by side, every matrix needs the same height. No matrices give an empty one.
+ */
+pub fn hstack<T: Clone>(matrices: &[&Matrix<T>]) -> Matrix<T> {
+    let Some(first) = matrices.first() else {
+        return Matrix::new();
+    };
+
+    if let Some((index, a)) = matrices.iter().enumerate().find(|(_, a)| a.height() != first.height()) {
+        panic!("Can't stack matrices horizontally: matrix {index} has height {}, expected {}", a.height(), first.height());
+    }
+
+    (0..first.height())
+        .map(|row| matrices.iter().flat_map(|a| a[row].iter().cloned()).collect::<Vec<T>>())
+        .collect()
+}
+
+// One under another, every matrix needs the same width
+pub fn vstack<T: Clone>(matrices: &[&Matrix<T>]) -> Matrix<T> {
+    let Some(first) = matrices.first() else {
+        return Matrix::new();
+    };
+
+    if let Some((index, a)) = matrices.iter().enumerate().find(|(_, a)| a.width() != first.width()) {
+        panic!("Can't stack matrices vertically: matrix {index} has width {}, expected {}", a.width(), first.width());
+    }
+
+    matrices.iter().flat_map(|a| a.row_iter()).collect()
+}
+
+// Same axis meaning as the reductions - Axis::Row joins within each row (hstack), Axis::Col within each column (vstack)
+pub fn concat<T: Clone>(matrices: &[&Matrix<T>], axis: Axis) -> Matrix<T> {
+    match axis {
+        Axis::Row => hstack(matrices),
+        Axis::Col => vstack(matrices)
+    }
+}
+
+/*
+ * Block matrix from rows of blocks, block![[a, b], [c, d]] is [[A, B], [C, D]].
+ * Blocks in a row need the same height and the block rows the same total width.
+ */
+#[macro_export]
+macro_rules! block {
+    ($([$($block: expr),+ $(,)?]),+ $(,)?) => {
+        $crate::shape::vstack(&[$(&$crate::shape::hstack(&[$(&$block),+])),+])
+    };
+}
+
+impl<T: Clone> Matrix<T> {
+
+    // Same elements in row-major order
+    pub fn reshape(&self, height: usize, width: usize) -> Matrix<T> {
+        if height * width != self.height() * self.width() {
+            panic!("Can't reshape a [{},{}] matrix into [{height},{width}]", self.height(), self.width());
+        }
+
+        Matrix::from_row_major(height, width, self.iter().cloned())
+    }
+
+    // Rows in the given order, indices can repeat
+    pub fn select_rows(&self, indices: &[usize]) -> Matrix<T> {
+        indices.iter().map(|&index| {
+            self.check_row(index);
+            &self[index]
+        }).collect()
+    }
+
+    pub fn select_cols(&self, indices: &[usize]) -> Matrix<T> {
+        indices.iter().for_each(|&index| self.check_col(index));
+        self.row_iter()
+            .map(|row| indices.iter().map(|&index| row[index].clone()).collect::<Vec<T>>())
+            .collect()
+    }
+
+    // Axis::Row reverses every row (left-right), Axis::Col every column (upside down)
+    pub fn flip(&self, axis: Axis) -> Matrix<T> {
+        match axis {
+            Axis::Row => self.row_iter().map(|row| row.iter().rev().cloned().collect::<Vec<T>>()).collect(),
+            Axis::Col => self.row_iter().rev().collect()
+        }
+    }
+
+    // Whole matrix repeated down x across times, built row by row so the shape follows repeat when a count is 0
+    pub fn tile(&self, down: usize, across: usize) -> Matrix<T> {
+        (0..down)
+            .flat_map(|_| self.row_iter())
+            .map(|row| row.iter().cycle().take(row.len() * across).cloned().collect::<Vec<T>>())
+            .collect()
+    }
+
+    // Every element repeated into a down x across block
+    pub fn repeat(&self, down: usize, across: usize) -> Matrix<T> {
+        self.row_iter()
+            .flat_map(|row| {
+                let row: Vec<T> = row.iter().flat_map(|value| iter::repeat_n(value.clone(), across)).collect();
+                iter::repeat_n(row, down)
+            })
+            .collect()
+    }
+
+    // An empty matrix takes its width from the row
+    pub fn insert_row(&mut self, index: usize, row: Vec<T>) {
+        if index > self.height() {
+            panic!("Row index out of bounds! {index} / {}", self.height());
+        }
+
+        if !self.is_empty() && row.len() != self.width() {
+            panic!("Row must have {} elements, got {}", self.width(), row.len());
+        }
+
+        self.rows_mut().insert(index, row);
+    }
+
+    // An empty matrix takes its height from the column
+    pub fn insert_col(&mut self, index: usize, col: Vec<T>) {
+        if index > self.width() {
+            panic!("Column index out of bounds! {index} / {}", self.width());
+        }
+
+        if self.is_empty() {
+            *self = col.into_iter().map(|value| vec![value]).collect();
+            return;
+        }
+
+        if col.len() != self.height() {
+            panic!("Column must have {} elements, got {}", self.height(), col.len());
+        }
+
+        for (row, value) in self.rows_mut().iter_mut().zip(col) {
+            row.insert(index, value);
+        }
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn swap_cols(&mut self, col_1: usize, col_2: usize) {
+        self.check_col(col_1);
+        self.check_col(col_2);
+        for row in self.rows_mut() {
+            row.swap(col_1, col_2);
+        }
+    }
+
+    pub fn remove_row(&mut self, index: usize) -> Vec<T> {
+        self.check_row(index);
+        self.rows_mut().remove(index)
+    }
+
+    // Removing the last column leaves rows with no elements, height stays the same
+    pub fn remove_col(&mut self, index: usize) -> Vec<T> {
+        self.check_col(index);
+        self.rows_mut().iter_mut().map(|row| row.remove(index)).collect()
+    }
+
+    fn check_row(&self, index: usize) {
+        if index >= self.height() {
+            panic!("Row index out of bounds! {index} / {}", self.height());
+        }
+    }
+
+    fn check_col(&self, index: usize) {
+        if index >= self.width() {
+            panic!("Column index out of bounds! {index} / {}", self.width());
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{lu_decomposition::{self, EliminationStorage}, matrix::*, matrix_traits::*, qr_factorization::{self, q_from_reflectors}, scalar::*};
+use core::{array, fmt, ops};
+
+/*
+ * Matrix with its size in the type, stored inline in arrays. Arithmetic and the LU based methods never
+ * allocate, only qr() and the conversions to and from Matrix do. Adding or multiplying matrices of
+ * mismatched sizes is a compile error instead of a panic.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SMatrix<T, const R: usize, const C: usize> {
+    data: [[T; C]; R]
+}
+
+pub type SMatrix2<T> = SMatrix<T, 2, 2>;
+pub type SMatrix3<T> = SMatrix<T, 3, 3>;
+pub type SMatrix4<T> = SMatrix<T, 4, 4>;
+
+impl<T, const R: usize, const C: usize> SMatrix<T, R, C> {
+    pub const fn new(data: [[T; C]; R]) -> Self {
+        SMatrix { data }
+    }
+
+    // Entry [row, col] is f(row, col)
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        SMatrix { data: array::from_fn(|row| array::from_fn(|col| f(row, col))) }
+    }
+
+    pub const fn height(&self) -> usize {
+        R
+    }
+
+    pub const fn width(&self) -> usize {
+        C
+    }
+
+    pub fn set(&mut self, row_index: usize, col_index: usize, value: T) {
+        if row_index >= R || col_index >= C {
+            panic!("Index out of bounds! [{row_index}, {col_index}] / [{R}, {C}]")
+        }
+
+        self.data[row_index][col_index] = value;
+    }
+
+    pub fn into_array(self) -> [[T; C]; R] {
+        self.data
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> SMatrix<T, R, C> {
+    pub fn new_fill(value: T) -> Self {
+        SMatrix::from_fn(|_, _| value.clone())
+    }
+
+    pub fn get(&self, row_index: usize, col_index: usize) -> T {
+        self.data[row_index][col_index].clone()
+    }
+
+    pub fn transpose(&self) -> SMatrix<T, C, R> {
+        SMatrix::from_fn(|row, col| self.get(col, row))
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from(self.data.clone())
+    }
+
+    // Panics if the size of A doesn't match the type
+    pub fn from_matrix(a: &Matrix<T>) -> Self {
+        if a.height() != R || a.width() != C {
+            panic!("Matrix doesn't fit the static size! A=[{},{}], expected=[{R},{C}]", a.height(), a.width());
+        }
+
+        SMatrix::from_fn(|row, col| a.get(row, col))
+    }
+}
+
+impl<T: MatrixZeroOne, const N: usize> SMatrix<T, N, N> {
+    pub fn identity() -> Self {
+        SMatrix::from_fn(|row, col| if row == col { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: Semiring, const R: usize, const C: usize> SMatrix<T, R, C> {
+    fn mul_matrix<const K: usize>(&self, rhs: &SMatrix<T, C, K>) -> SMatrix<T, R, K> {
+
+        // Starts from 0 instead of the first product, C can be 0
+        SMatrix::from_fn(|row, col| (0..C).fold(T::zero(), |acc, i| acc.add(&self.data[row][i].mul(&rhs.data[i][col]))))
+    }
+}
+
+///
+/// Decompositions - the results are fixed size too
+///
+// PA = LU, row i of PA is row p[i] of A
+pub struct SLUResult<T, const N: usize> {
+    pub l: SMatrix<T, N, N>,
+    pub u: SMatrix<T, N, N>,
+    pub p: [usize; N]
+}
+
+// A = QR, Q is unitary
+pub struct SQRResult<T, const R: usize, const C: usize> {
+    pub q: SMatrix<T, R, R>,
+    pub r: SMatrix<T, R, C>
+}
+
+impl<T: Field, const N: usize> SMatrix<T, N, N> {
+
+    // Same elimination as lu_gauss, run on the arrays
+    pub fn lu(&self) -> SLUResult<T, N> {
+        let mut l = SMatrix::new_fill(T::zero());
+        let mut u = self.clone();
+        let mut p = array::from_fn(|index| index);
+        lu_decomposition::eliminate(&mut u, &mut l, N, N, |row_1, row_2| p.swap(row_1, row_2));
+
+        // Same as UpperTriangular::from_matrix, whatever a skipped column kept below the diagonal is dropped
+        for row in 0..N {
+            for col in 0..row {
+                u[(row, col)] = T::zero();
+            }
+        }
+
+        SLUResult { l, u, p }
+    }
+
+    pub fn det(&self) -> T {
+        self.lu().det()
+    }
+
+    pub fn inv(&self) -> Self {
+        self.lu().solve(&SMatrix::identity())
+    }
+
+    // Solves AX = B for every column of B at once
+    pub fn solve<const K: usize>(&self, b: &SMatrix<T, N, K>) -> SMatrix<T, N, K> {
+        self.lu().solve(b)
+    }
+}
+
+impl<T: Field, const N: usize> SLUResult<T, N> {
+
+    // LUx = Pb - forward substitution with L (ones on the diagonal), then backward with U
+    pub fn solve<const K: usize>(&self, b: &SMatrix<T, N, K>) -> SMatrix<T, N, K> {
+        let mut x = SMatrix::from_fn(|row, col| b[(self.p[row], col)].clone());
+        for col in 0..K {
+            for row in 0..N {
+                for i in 0..row {
+                    x[(row, col)] = x[(row, col)].sub(&self.l[(row, i)].mul(&x[(i, col)]));
+                }
+            }
+
+            for row in (0..N).rev() {
+                for i in (row + 1)..N {
+                    x[(row, col)] = x[(row, col)].sub(&self.u[(row, i)].mul(&x[(i, col)]));
+                }
+                x[(row, col)] = x[(row, col)].div(&self.u[(row, row)]);
+            }
+        }
+        x
+    }
+
+    // det(A) = det(P) det(U), every swap that sorts p back flips the sign
+    pub fn det(&self) -> T {
+        let mut ret = (0..N).fold(T::one(), |acc, index| acc.mul(&self.u[(index, index)]));
+        let mut p = self.p;
+        for index in 0..N {
+            while p[index] != index {
+                let target = p[index];
+                p.swap(index, target);
+                ret = ret.neg();
+            }
+        }
+        ret
+    }
+}
+
+impl<T: ComplexField, const R: usize, const C: usize> SMatrix<T, R, C> {
+
+    // Goes through qr(), its reflectors are full size matrices
+    pub fn qr(&self) -> SQRResult<T, R, C> {
+        let qr = qr_factorization::qr(&self.to_matrix());
+        let q = if qr.h.is_empty() { SMatrix::identity() } else { SMatrix::from_matrix(&q_from_reflectors(&qr.h)) };
+        SQRResult { q, r: SMatrix::from_matrix(&qr.r) }
+    }
+
+    pub fn frobenius_norm(&self) -> T::Real {
+        self.data.iter().flatten().fold(T::Real::zero(), |acc, value| {
+            let modulus = value.modulus();
+            acc.add(&modulus.mul(&modulus))
+        }).sqrt()
+    }
+}
+
+// Same indexing as Matrix, A[(row, col)] and A[row]
+impl<T, const R: usize, const C: usize> ops::Index<(usize, usize)> for SMatrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row_index, col_index): (usize, usize)) -> &Self::Output {
+        &self.data[row_index][col_index]
+    }
+}
+
+impl<T, const R: usize, const C: usize> ops::IndexMut<(usize, usize)> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, (row_index, col_index): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[row_index][col_index]
+    }
+}
+
+impl<T, const R: usize, const C: usize> ops::Index<usize> for SMatrix<T, R, C> {
+    type Output = [T; C];
+
+    fn index(&self, row_index: usize) -> &Self::Output {
+        &self.data[row_index]
+    }
+}
+
+impl<T, const R: usize, const C: usize> ops::IndexMut<usize> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, row_index: usize) -> &mut Self::Output {
+        &mut self.data[row_index]
+    }
+}
+
+impl<T, const R: usize, const C: usize> EliminationStorage<T> for SMatrix<T, R, C> {
+    fn swap_rows(&mut self, row_1: usize, row_2: usize) {
+        self.data.swap(row_1, row_2);
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for SMatrix<T, R, C> {
+    fn from(data: [[T; C]; R]) -> Self {
+        SMatrix { data }
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Matrix<T> {
+    fn from(a: SMatrix<T, R, C>) -> Self {
+        Matrix::from(a.data)
+    }
+}
+
+///
+/// Operators
+///
+impl<T: Clone + MatrixElem, const R: usize, const C: usize> ops::Add for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        SMatrix::from_fn(|row, col| self.data[row][col].add(&rhs.data[row][col]))
+    }
+}
+
+impl<T: Clone + MatrixSub, const R: usize, const C: usize> ops::Sub for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        SMatrix::from_fn(|row, col| self.data[row][col].sub(&rhs.data[row][col]))
+    }
+}
+
+// Matrix * Scalar
+impl<T: Clone + MatrixElem, const R: usize, const C: usize> ops::Mul<T> for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        SMatrix::from_fn(|row, col| self.data[row][col].mul(&rhs))
+    }
+}
+
+// RxC * CxK, only implemented when the inner sizes agree
+impl<T: Semiring, const R: usize, const C: usize, const K: usize> ops::Mul<SMatrix<T, C, K>> for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, K>;
+
+    fn mul(self, rhs: SMatrix<T, C, K>) -> Self::Output {
+        self.mul_matrix(&rhs)
+    }
+}
+
+impl<T: Semiring, const R: usize, const C: usize, const K: usize> ops::Mul<&SMatrix<T, C, K>> for &SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, K>;
+
+    fn mul(self, rhs: &SMatrix<T, C, K>) -> Self::Output {
+        self.mul_matrix(rhs)
+    }
+}
+
+impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for SMatrix<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row, vector) in self.data.iter().enumerate() {
+            for (col, value) in vector.iter().enumerate() {
+                match f.precision() {
+                    Some(precision) => { write!(f, "{:.*}", precision, value)? },
+                    None => { write!(f, "{}", value)? }
+                }
+
+                if col < C - 1 {
+                    write!(f, ",")?
+                }
+            }
+
+            if row < R - 1 {
+                writeln!(f)?
+            }
+        }
+
+        Ok(())
+    }
+}
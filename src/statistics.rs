@@ -0,0 +1,245 @@
+use crate::{matrix::*, matrix_traits::*, prelude::*, scalar::*};
+use core::cmp::Ordering;
+
+/*
+ * Direction of a reduction. Axis::Row works within each row and gives one value per row
+ * (a column vector), Axis::Col works within each column and gives a row vector.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Row,
+    Col
+}
+
+///
+/// Helpers over any sequence of elements
+///
+fn sum<'a, T: Semiring + 'a>(values: impl Iterator<Item = &'a T>) -> T {
+    values.fold(T::zero(), |acc, value| acc.add(value))
+}
+
+fn product<'a, T: Semiring + 'a>(values: impl Iterator<Item = &'a T>) -> T {
+    values.fold(T::one(), |acc, value| acc.mul(value))
+}
+
+// n as an element of T, by adding up ones so that exact types stay exact
+fn count<T: Field>(n: usize) -> T {
+    (0..n).fold(T::zero(), |acc, _| acc.add(&T::one()))
+}
+
+fn mean<T: Field>(values: &[&T]) -> T {
+    if values.is_empty() {
+        panic!("Cannot compute mean of no values");
+    }
+
+    sum(values.iter().copied()).div(&count(values.len()))
+}
+
+// Sample covariance, divided by n - 1
+fn covariance<T: Field>(a: &[&T], b: &[&T]) -> T {
+    if a.len() < 2 {
+        panic!("Sample variance needs at least 2 values, got {}", a.len());
+    }
+
+    let (mean_a, mean_b) = (mean(a), mean(b));
+    let products: Vec<T> = a.iter().zip(b).map(|(x, y)| x.sub(&mean_a).mul(&y.sub(&mean_b))).collect();
+    sum(products.iter()).div(&count(a.len() - 1))
+}
+
+// Position of the first element that no other element beats, elements not comparable even to themselves (NaN) are skipped
+fn arg_best<'a, T: PartialOrd + 'a>(values: impl Iterator<Item = &'a T>, better: Ordering) -> Option<usize> {
+    let mut best: Option<(usize, &T)> = None;
+    for (index, value) in values.enumerate() {
+        if value.partial_cmp(value).is_none() {
+            continue;
+        }
+
+        if best.is_none_or(|(_, b)| value.partial_cmp(b) == Some(better)) {
+            best = Some((index, value));
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+impl<T> Matrix<T> {
+
+    // Every row or every column as a list of references
+    fn lanes(&self, axis: Axis) -> Vec<Vec<&T>> {
+        match axis {
+            Axis::Row => self.row_iter().map(|row| row.iter().collect()).collect(),
+            Axis::Col => self.col_iter().map(|col| col.collect()).collect()
+        }
+    }
+
+    // One value per lane, shaped as a column vector for rows and a row vector for columns
+    fn reduce<U>(&self, axis: Axis, f: impl FnMut(Vec<&T>) -> U) -> Matrix<U> {
+        let values: Vec<U> = self.lanes(axis).into_iter().map(f).collect();
+        match axis {
+            Axis::Row => Matrix::from_row_major(values.len(), 1, values),
+            Axis::Col => Matrix::from_row_major(1, values.len(), values)
+        }
+    }
+
+    fn check_lanes_not_empty(&self, axis: Axis) {
+        let empty = match axis {
+            Axis::Row => self.width() == 0,
+            Axis::Col => self.height() == 0
+        };
+        if empty && !self.is_empty() {
+            panic!("Cannot reduce empty rows or columns! A=[{},{}]", self.height(), self.width());
+        }
+    }
+}
+
+///
+/// Sums and products
+///
+impl<T: Semiring> Matrix<T> {
+    pub fn sum(&self) -> T {
+        sum(self.iter())
+    }
+
+    pub fn sum_axis(&self, axis: Axis) -> Matrix<T> {
+        self.reduce(axis, |lane| sum(lane.into_iter()))
+    }
+
+    pub fn product(&self) -> T {
+        product(self.iter())
+    }
+
+    pub fn product_axis(&self, axis: Axis) -> Matrix<T> {
+        self.reduce(axis, |lane| product(lane.into_iter()))
+    }
+
+    pub fn trace(&self) -> T {
+        if self.height() != self.width() {
+            panic!("Cannot compute trace of a non-square matrix! A=[{},{}]", self.height(), self.width());
+        }
+
+        sum(self.diag_iter())
+    }
+}
+
+impl<T: Clone + MatrixElem> Matrix<T> {
+
+    // Running sums within each row or column, the first element is kept as it is
+    pub fn cumsum(&self, axis: Axis) -> Matrix<T> {
+        let mut ret = self.clone();
+        match axis {
+            Axis::Row => for row in 0..self.height() {
+                for col in 1..self.width() {
+                    ret[(row, col)] = ret[(row, col - 1)].add(&self[(row, col)]);
+                }
+            },
+            Axis::Col => for row in 1..self.height() {
+                for col in 0..self.width() {
+                    ret[(row, col)] = ret[(row - 1, col)].add(&self[(row, col)]);
+                }
+            }
+        }
+        ret
+    }
+}
+
+///
+/// Extremes - None or a panic for empty matrices, NaNs are skipped
+///
+impl<T: Clone + PartialOrd> Matrix<T> {
+    pub fn min(&self) -> Option<T> {
+        self.argmin().map(|index| self[index].clone())
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.argmax().map(|index| self[index].clone())
+    }
+
+    // (row, col) of the first smallest element
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        arg_best(self.iter(), Ordering::Less).map(|index| (index / self.width(), index % self.width()))
+    }
+
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        arg_best(self.iter(), Ordering::Greater).map(|index| (index / self.width(), index % self.width()))
+    }
+
+    pub fn min_axis(&self, axis: Axis) -> Matrix<T> {
+        self.check_lanes_not_empty(axis);
+        self.reduce(axis, |lane| lane[arg_best(lane.iter().copied(), Ordering::Less).unwrap_or(0)].clone())
+    }
+
+    pub fn max_axis(&self, axis: Axis) -> Matrix<T> {
+        self.check_lanes_not_empty(axis);
+        self.reduce(axis, |lane| lane[arg_best(lane.iter().copied(), Ordering::Greater).unwrap_or(0)].clone())
+    }
+
+    // Index within each row (column index) or within each column (row index)
+    pub fn argmin_axis(&self, axis: Axis) -> Vec<usize> {
+        self.check_lanes_not_empty(axis);
+        self.lanes(axis).into_iter().map(|lane| arg_best(lane.into_iter(), Ordering::Less).unwrap_or(0)).collect()
+    }
+
+    pub fn argmax_axis(&self, axis: Axis) -> Vec<usize> {
+        self.check_lanes_not_empty(axis);
+        self.lanes(axis).into_iter().map(|lane| arg_best(lane.into_iter(), Ordering::Greater).unwrap_or(0)).collect()
+    }
+}
+
+///
+/// Statistics - variances are sample variances, divided by n - 1
+///
+impl<T: Field> Matrix<T> {
+    pub fn mean(&self) -> T {
+        mean(&self.iter().collect::<Vec<_>>())
+    }
+
+    pub fn mean_axis(&self, axis: Axis) -> Matrix<T> {
+        self.reduce(axis, |lane| mean(&lane))
+    }
+
+    pub fn variance(&self) -> T {
+        let values: Vec<&T> = self.iter().collect();
+        covariance(&values, &values)
+    }
+
+    pub fn variance_axis(&self, axis: Axis) -> Matrix<T> {
+        self.reduce(axis, |lane| covariance(&lane, &lane))
+    }
+
+    /*
+     * Columns are variables and rows are observations. Entry [i, j] is the covariance
+     * of columns i and j, so the diagonal holds the variances of the columns.
+     */
+    pub fn covariance(&self) -> Matrix<T> {
+        let columns = self.lanes(Axis::Col);
+        let mut ret = Matrix::new_fill(self.width(), self.width(), T::zero());
+        for i in 0..columns.len() {
+            for j in i..columns.len() {
+                let value = covariance(&columns[i], &columns[j]);
+                ret[(j, i)] = value.clone();
+                ret[(i, j)] = value;
+            }
+        }
+        ret
+    }
+}
+
+impl<T: RealField> Matrix<T> {
+    pub fn std(&self) -> T {
+        self.variance().sqrt()
+    }
+
+    pub fn std_axis(&self, axis: Axis) -> Matrix<T> {
+        self.variance_axis(axis).map(T::sqrt)
+    }
+
+    // Covariance scaled to [-1, 1], columns with no variance give NaN like a 0/0 would
+    pub fn correlation(&self) -> Matrix<T> {
+        let covariance = self.covariance();
+        let deviations: Vec<T> = covariance.diag_iter().map(T::sqrt).collect();
+        let mut ret = covariance.clone();
+        for ((row, col), value) in covariance.indexed_iter() {
+            ret[(row, col)] = value.div(&deviations[row].mul(&deviations[col]));
+        }
+        ret
+    }
+}
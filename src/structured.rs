@@ -0,0 +1,774 @@
+use crate::{lu_decomposition::*, matrix::*, matrix_traits::*, prelude::*, scalar::*, triangular_solve::*};
+use core::{fmt, ops};
+
+// Square matrix with only the diagonal stored
+pub struct Diagonal<T> {
+    diag: Vec<T>
+}
+
+// Square matrix with zeros above the diagonal - row i stores columns 0..=i
+pub struct LowerTriangular<T> {
+    rows: Vec<Vec<T>>
+}
+
+// Matrix with zeros below the diagonal - row i stores columns i..width
+pub struct UpperTriangular<T> {
+    rows: Vec<Vec<T>>,
+    width: usize
+}
+
+// Square matrix equal to its transpose - only the lower triangle is stored
+pub struct Symmetric<T> {
+    rows: Vec<Vec<T>>
+}
+
+// Row permutation - row i of P*A is row perm[i] of A
+#[derive(Clone)]
+pub struct Permutation {
+    perm: Vec<usize>
+}
+
+fn product<'a, T: Ring + 'a>(values: impl Iterator<Item = &'a T>) -> T {
+    values.fold(T::one(), |acc, value| acc.mul(value))
+}
+
+fn check_square<T>(a: &Matrix<T>, name: &str) {
+    if a.height() != a.width() {
+        panic!("{name} matrix must be square! A=[{},{}]", a.height(), a.width());
+    }
+}
+
+///
+/// Diagonal
+///
+impl<T> Diagonal<T> {
+    pub fn new(diag: Vec<T>) -> Self {
+        Diagonal { diag }
+    }
+
+    pub fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    pub fn diagonal(&self) -> &[T] {
+        &self.diag
+    }
+}
+
+impl<T: Clone> Diagonal<T> {
+    pub fn from_matrix(a: &Matrix<T>) -> Self {
+        check_square(a, "Diagonal");
+        Diagonal { diag: (0..a.height()).map(|i| a.get(i, i)).collect() }
+    }
+}
+
+impl<T: Clone> Clone for Diagonal<T> {
+    fn clone(&self) -> Self {
+        Diagonal { diag: self.diag.clone() }
+    }
+}
+
+impl<T: MatrixZeroOne> Diagonal<T> {
+    pub fn identity(size: usize) -> Self {
+        Diagonal { diag: (0..size).map(|_| T::one()).collect() }
+    }
+}
+
+impl<T: Clone + MatrixZeroOne> Diagonal<T> {
+    pub fn get(&self, row_index: usize, col_index: usize) -> T {
+        if row_index >= self.size() || col_index >= self.size() {
+            panic!("Index out of bounds! [{row_index}, {col_index}] / [{}, {}]", self.size(), self.size())
+        }
+
+        if row_index == col_index { self.diag[row_index].clone() } else { T::zero() }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut ret = Matrix::new_fill(self.size(), self.size(), T::zero());
+        for (index, value) in self.diag.iter().enumerate() {
+            ret.set(index, index, value.clone());
+        }
+        ret
+    }
+}
+
+impl<T: Clone + MatrixElem> Diagonal<T> {
+
+    // Scales every row of rhs by the matching diagonal element
+    fn mul_matrix(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        if self.size() != rhs.height() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.size(), rhs.height())
+        }
+
+        let mut ret = Matrix::with_capacity(rhs.height(), rhs.width());
+        for row_index in 0..rhs.height() {
+            for col_index in 0..rhs.width() {
+                ret.push_to_row(row_index, self.diag[row_index].mul(&rhs.rows(row_index)[col_index]));
+            }
+        }
+        ret
+    }
+
+    fn mul_diagonal(&self, rhs: &Diagonal<T>) -> Diagonal<T> {
+        if self.size() != rhs.size() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.size(), rhs.size())
+        }
+
+        Diagonal { diag: self.diag.iter().zip(&rhs.diag).map(|(a, b)| a.mul(b)).collect() }
+    }
+}
+
+impl<T: Field> Diagonal<T> {
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T> {
+        self.inverse().mul_matrix(b)
+    }
+
+    pub fn inverse(&self) -> Diagonal<T> {
+        Diagonal { diag: self.diag.iter().map(|x| x.recip()).collect() }
+    }
+
+    pub fn determinant(&self) -> T {
+        product(self.diag.iter())
+    }
+}
+
+///
+/// Lower triangular
+///
+impl<T> LowerTriangular<T> {
+    pub fn size(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl<T: Clone> LowerTriangular<T> {
+
+    // Takes the lower triangle of A, everything above the diagonal is ignored
+    pub fn from_matrix(a: &Matrix<T>) -> Self {
+        check_square(a, "Triangular");
+        LowerTriangular { rows: (0..a.height()).map(|row| a.rows(row)[..=row].to_vec()).collect() }
+    }
+
+    pub fn transpose(&self) -> UpperTriangular<T> {
+        let size = self.size();
+        UpperTriangular {
+            rows: (0..size).map(|row| (row..size).map(|col| self.rows[col][row].clone()).collect()).collect(),
+            width: size
+        }
+    }
+}
+
+impl<T: Clone> Clone for LowerTriangular<T> {
+    fn clone(&self) -> Self {
+        LowerTriangular { rows: self.rows.clone() }
+    }
+}
+
+impl<T: Clone + MatrixZeroOne> LowerTriangular<T> {
+    pub fn get(&self, row_index: usize, col_index: usize) -> T {
+        if row_index >= self.size() || col_index >= self.size() {
+            panic!("Index out of bounds! [{row_index}, {col_index}] / [{}, {}]", self.size(), self.size())
+        }
+
+        if col_index <= row_index { self.rows[row_index][col_index].clone() } else { T::zero() }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut ret = Matrix::new_fill(self.size(), self.size(), T::zero());
+        for (row_index, row) in self.rows.iter().enumerate() {
+            for (col_index, value) in row.iter().enumerate() {
+                ret.set(row_index, col_index, value.clone());
+            }
+        }
+        ret
+    }
+}
+
+impl<T: Clone + MatrixElem + MatrixZeroOne> LowerTriangular<T> {
+    fn mul_matrix(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        if self.size() != rhs.height() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.size(), rhs.height())
+        }
+
+        let mut ret = Matrix::with_capacity(self.size(), rhs.width());
+        for row_index in 0..self.size() {
+            for col_index in 0..rhs.width() {
+
+                // Only columns up to the diagonal contribute
+                let mut new_val = T::zero();
+                for (i, value) in self.rows[row_index].iter().enumerate() {
+                    new_val = new_val.add(&value.mul(&rhs.rows(i)[col_index]));
+                }
+                ret.push_to_row(row_index, new_val);
+            }
+        }
+        ret
+    }
+
+    fn mul_lower(&self, rhs: &LowerTriangular<T>) -> LowerTriangular<T> {
+        if self.size() != rhs.size() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.size(), rhs.size())
+        }
+
+        // Product of lower triangular matrices is lower triangular, (i, j) only sums over j..=i
+        let rows = (0..self.size()).map(|row| {
+            (0..=row).map(|col| {
+                let mut new_val = T::zero();
+                for i in col..=row {
+                    new_val = new_val.add(&self.rows[row][i].mul(&rhs.rows[i][col]));
+                }
+                new_val
+            }).collect()
+        }).collect();
+
+        LowerTriangular { rows }
+    }
+
+    fn mul_upper(&self, rhs: &UpperTriangular<T>) -> Matrix<T> {
+        if self.size() != rhs.height() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.size(), rhs.height())
+        }
+
+        let mut ret = Matrix::with_capacity(self.size(), rhs.width());
+        for row_index in 0..self.size() {
+            for col_index in 0..rhs.width() {
+                let mut new_val = T::zero();
+                for i in 0..=row_index.min(col_index) {
+                    new_val = new_val.add(&self.rows[row_index][i].mul(&rhs.rows[i][col_index - i]));
+                }
+                ret.push_to_row(row_index, new_val);
+            }
+        }
+        ret
+    }
+}
+
+impl<T> TriangularView<T> for LowerTriangular<T> {
+    fn triangle_size(&self) -> usize {
+        self.size()
+    }
+
+    fn triangle_entry(&self, row_index: usize, col_index: usize) -> &T {
+        &self.rows[row_index][col_index]
+    }
+}
+
+impl<T: Field> LowerTriangular<T> {
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T> {
+        solve_lower_triangular(self, b, TriangularSolveOptions::default())
+    }
+
+    pub fn inverse(&self) -> LowerTriangular<T> {
+        LowerTriangular::from_matrix(&self.solve(&Matrix::identity(self.size())))
+    }
+
+    pub fn determinant(&self) -> T {
+        product(self.rows.iter().map(|row| &row[row.len() - 1]))
+    }
+}
+
+///
+/// Upper triangular
+///
+impl<T> UpperTriangular<T> {
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    fn check_square(&self) {
+        if self.height() != self.width() {
+            panic!("Triangular matrix must be square! U=[{},{}]", self.height(), self.width());
+        }
+    }
+}
+
+impl<T: Clone> UpperTriangular<T> {
+
+    // Takes the upper triangle (or trapezoid) of A, everything below the diagonal is ignored
+    pub fn from_matrix(a: &Matrix<T>) -> Self {
+        let width = a.width();
+        UpperTriangular {
+            rows: (0..a.height()).map(|row| a.rows(row)[row.min(width)..].to_vec()).collect(),
+            width
+        }
+    }
+
+    pub fn transpose(&self) -> LowerTriangular<T> {
+        self.check_square();
+        LowerTriangular {
+            rows: (0..self.height()).map(|row| (0..=row).map(|col| self.rows[col][row - col].clone()).collect()).collect()
+        }
+    }
+}
+
+impl<T: Clone> Clone for UpperTriangular<T> {
+    fn clone(&self) -> Self {
+        UpperTriangular { rows: self.rows.clone(), width: self.width }
+    }
+}
+
+impl<T: Clone + MatrixZeroOne> UpperTriangular<T> {
+    pub fn get(&self, row_index: usize, col_index: usize) -> T {
+        if row_index >= self.height() || col_index >= self.width() {
+            panic!("Index out of bounds! [{row_index}, {col_index}] / [{}, {}]", self.height(), self.width())
+        }
+
+        if col_index >= row_index { self.rows[row_index][col_index - row_index].clone() } else { T::zero() }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut ret = Matrix::new_fill(self.height(), self.width(), T::zero());
+        for (row_index, row) in self.rows.iter().enumerate() {
+            for (i, value) in row.iter().enumerate() {
+                ret.set(row_index, row_index + i, value.clone());
+            }
+        }
+        ret
+    }
+}
+
+impl<T: Clone + MatrixElem + MatrixZeroOne> UpperTriangular<T> {
+    fn mul_matrix(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        if self.width() != rhs.height() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.width(), rhs.height())
+        }
+
+        let mut ret = Matrix::with_capacity(self.height(), rhs.width());
+        for row_index in 0..self.height() {
+            for col_index in 0..rhs.width() {
+
+                // Only columns from the diagonal onwards contribute
+                let mut new_val = T::zero();
+                for (i, value) in self.rows[row_index].iter().enumerate() {
+                    new_val = new_val.add(&value.mul(&rhs.rows(row_index + i)[col_index]));
+                }
+                ret.push_to_row(row_index, new_val);
+            }
+        }
+        ret
+    }
+
+    fn mul_upper(&self, rhs: &UpperTriangular<T>) -> UpperTriangular<T> {
+        if self.width() != rhs.height() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.width(), rhs.height())
+        }
+
+        // Product of upper triangular matrices is upper triangular, (i, j) only sums over i..=j
+        let rows = (0..self.height()).map(|row| {
+            (row.min(rhs.width())..rhs.width()).map(|col| {
+                let mut new_val = T::zero();
+                for i in row..(col + 1).min(self.width()) {
+                    new_val = new_val.add(&self.rows[row][i - row].mul(&rhs.rows[i][col - i]));
+                }
+                new_val
+            }).collect()
+        }).collect();
+
+        UpperTriangular { rows, width: rhs.width() }
+    }
+}
+
+impl<T> TriangularView<T> for UpperTriangular<T> {
+    fn triangle_size(&self) -> usize {
+        self.check_square();
+        self.height()
+    }
+
+    fn triangle_entry(&self, row_index: usize, col_index: usize) -> &T {
+        &self.rows[row_index][col_index - row_index]
+    }
+}
+
+impl<T: Field> UpperTriangular<T> {
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T> {
+        solve_upper_triangular(self, b, TriangularSolveOptions::default())
+    }
+
+    pub fn inverse(&self) -> UpperTriangular<T> {
+        UpperTriangular::from_matrix(&self.solve(&Matrix::identity(self.height())))
+    }
+
+    pub fn determinant(&self) -> T {
+        self.check_square();
+        product(self.rows.iter().map(|row| &row[0]))
+    }
+}
+
+///
+/// Symmetric
+///
+impl<T> Symmetric<T> {
+    pub fn size(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl<T: Clone> Symmetric<T> {
+
+    // Mirrors the lower triangle of A, everything above the diagonal is ignored
+    pub fn from_lower(a: &Matrix<T>) -> Self {
+        check_square(a, "Symmetric");
+        Symmetric { rows: (0..a.height()).map(|row| a.rows(row)[..=row].to_vec()).collect() }
+    }
+
+    pub fn get(&self, row_index: usize, col_index: usize) -> T {
+        if row_index >= self.size() || col_index >= self.size() {
+            panic!("Index out of bounds! [{row_index}, {col_index}] / [{}, {}]", self.size(), self.size())
+        }
+
+        if col_index <= row_index {
+            self.rows[row_index][col_index].clone()
+        }
+        else {
+            self.rows[col_index][row_index].clone()
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut ret = Matrix::with_capacity(self.size(), self.size());
+        for row_index in 0..self.size() {
+            for col_index in 0..self.size() {
+                ret.push_to_row(row_index, self.get(row_index, col_index));
+            }
+        }
+        ret
+    }
+}
+
+impl<T: Clone + PartialEq> Symmetric<T> {
+    pub fn from_matrix(a: &Matrix<T>) -> Self {
+        check_square(a, "Symmetric");
+        for row in 0..a.height() {
+            for col in 0..row {
+                if a.get(row, col) != a.get(col, row) {
+                    panic!("Matrix is not symmetric! A[{row}, {col}] != A[{col}, {row}]");
+                }
+            }
+        }
+        Symmetric::from_lower(a)
+    }
+}
+
+impl<T: Clone> Clone for Symmetric<T> {
+    fn clone(&self) -> Self {
+        Symmetric { rows: self.rows.clone() }
+    }
+}
+
+impl<T: Clone + MatrixElem + MatrixZeroOne> Symmetric<T> {
+    fn mul_matrix(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        if self.size() != rhs.height() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.size(), rhs.height())
+        }
+
+        let mut ret = Matrix::with_capacity(self.size(), rhs.width());
+        for row_index in 0..self.size() {
+            for col_index in 0..rhs.width() {
+                let mut new_val = T::zero();
+                for i in 0..self.size() {
+                    new_val = new_val.add(&self.get(row_index, i).mul(&rhs.rows(i)[col_index]));
+                }
+                ret.push_to_row(row_index, new_val);
+            }
+        }
+        ret
+    }
+}
+
+// There is no specialized factorization for symmetric matrices yet, these go through LU
+impl<T: Field> Symmetric<T> {
+    pub fn solve(&self, b: &Matrix<T>) -> Matrix<T> {
+        lu_solve(&lu_gauss(&self.to_matrix()), b)
+    }
+
+    pub fn inverse(&self) -> Symmetric<T> {
+        Symmetric::from_lower(&inv(&self.to_matrix()))
+    }
+
+    pub fn determinant(&self) -> T {
+        let lu = lu_gauss(&self.to_matrix());
+        let det = lu.u.determinant();
+        if lu.p.determinant() < 0 { det.neg() } else { det }
+    }
+}
+
+///
+/// Permutation
+///
+impl Permutation {
+    pub fn identity(size: usize) -> Self {
+        Permutation { perm: (0..size).collect() }
+    }
+
+    pub fn from_vec(perm: Vec<usize>) -> Self {
+        let mut seen = vec![false; perm.len()];
+        for &index in &perm {
+            if index >= perm.len() || seen[index] {
+                panic!("Not a permutation of 0..{}: {:?}", perm.len(), perm);
+            }
+            seen[index] = true;
+        }
+        Permutation { perm }
+    }
+
+    pub fn size(&self) -> usize {
+        self.perm.len()
+    }
+
+    // Row i of P*A is row indices()[i] of A
+    pub fn indices(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn swap(&mut self, row_1: usize, row_2: usize) {
+        self.perm.swap(row_1, row_2);
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.perm.iter().enumerate().all(|(i, &index)| i == index)
+    }
+
+    pub fn inverse(&self) -> Permutation {
+        let mut perm = vec![0; self.size()];
+        for (i, &index) in self.perm.iter().enumerate() {
+            perm[index] = i;
+        }
+        Permutation { perm }
+    }
+
+    pub fn transpose(&self) -> Permutation {
+        self.inverse()
+    }
+
+    // Sign of the permutation, computed from the number of cycles
+    pub fn determinant(&self) -> i32 {
+        let mut visited = vec![false; self.size()];
+        let mut cycles = 0;
+        for start in 0..self.size() {
+            if visited[start] {
+                continue;
+            }
+
+            cycles += 1;
+            let mut index = start;
+            while !visited[index] {
+                visited[index] = true;
+                index = self.perm[index];
+            }
+        }
+
+        if (self.size() - cycles).is_multiple_of(2) { 1 } else { -1 }
+    }
+
+    pub fn solve<T: Clone>(&self, b: &Matrix<T>) -> Matrix<T> {
+        self.inverse().mul_matrix(b)
+    }
+
+    pub fn to_matrix<T: MatrixZeroOne>(&self) -> Matrix<T> {
+        let mut ret = Matrix::with_capacity(self.size(), self.size());
+        for (row_index, &index) in self.perm.iter().enumerate() {
+            for col_index in 0..self.size() {
+                ret.push_to_row(row_index, if col_index == index { T::one() } else { T::zero() });
+            }
+        }
+        ret
+    }
+
+    fn mul_matrix<T: Clone>(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        if self.size() != rhs.height() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.size(), rhs.height())
+        }
+
+        let mut ret = Matrix::with_capacity(rhs.height(), rhs.width());
+        for (row_index, &index) in self.perm.iter().enumerate() {
+            for col_index in 0..rhs.width() {
+                ret.push_to_row(row_index, rhs.get(index, col_index));
+            }
+        }
+        ret
+    }
+
+    fn mul_permutation(&self, rhs: &Permutation) -> Permutation {
+        if self.size() != rhs.size() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.size(), rhs.size())
+        }
+
+        Permutation { perm: self.perm.iter().map(|&index| rhs.perm[index]).collect() }
+    }
+}
+
+///
+/// Matrix on the left side
+///
+impl<T: Clone + MatrixElem> Matrix<T> {
+
+    // Scales every column by the matching diagonal element
+    fn mul_diagonal(&self, rhs: &Diagonal<T>) -> Matrix<T> {
+        if self.width() != rhs.size() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.width(), rhs.size())
+        }
+
+        let mut ret = Matrix::with_capacity(self.height(), self.width());
+        for row_index in 0..self.height() {
+            for col_index in 0..self.width() {
+                ret.push_to_row(row_index, self.rows(row_index)[col_index].mul(&rhs.diag[col_index]));
+            }
+        }
+        ret
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+
+    // Column j of A*P is column P^-1[j] of A
+    fn mul_permutation(&self, rhs: &Permutation) -> Matrix<T> {
+        if self.width() != rhs.size() {
+            panic!("Can't multiply matrices AxB: A.width ({}) != B.height ({})", self.width(), rhs.size())
+        }
+
+        let inverse = rhs.inverse();
+        let mut ret = Matrix::with_capacity(self.height(), self.width());
+        for row_index in 0..self.height() {
+            for &index in &inverse.perm {
+                ret.push_to_row(row_index, self.get(row_index, index));
+            }
+        }
+        ret
+    }
+}
+
+///
+/// Operators
+///
+macro_rules! structured_mul_impl(
+    ($lhs: ident, $rhs: ident, $out: ident, $method: ident) => {
+        impl<T: Clone + MatrixElem + MatrixZeroOne> ops::Mul<$rhs<T>> for $lhs<T> {
+            type Output = $out<T>;
+
+            fn mul(self, rhs: $rhs<T>) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<T: Clone + MatrixElem + MatrixZeroOne> ops::Mul<&$rhs<T>> for &$lhs<T> {
+            type Output = $out<T>;
+
+            fn mul(self, rhs: &$rhs<T>) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+    }
+);
+
+structured_mul_impl!(Diagonal, Matrix, Matrix, mul_matrix);
+structured_mul_impl!(Diagonal, Diagonal, Diagonal, mul_diagonal);
+structured_mul_impl!(Matrix, Diagonal, Matrix, mul_diagonal);
+structured_mul_impl!(LowerTriangular, Matrix, Matrix, mul_matrix);
+structured_mul_impl!(LowerTriangular, LowerTriangular, LowerTriangular, mul_lower);
+structured_mul_impl!(LowerTriangular, UpperTriangular, Matrix, mul_upper);
+structured_mul_impl!(UpperTriangular, Matrix, Matrix, mul_matrix);
+structured_mul_impl!(UpperTriangular, UpperTriangular, UpperTriangular, mul_upper);
+structured_mul_impl!(Symmetric, Matrix, Matrix, mul_matrix);
+
+// P * Matrix
+impl<T: Clone> ops::Mul<Matrix<T>> for Permutation {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        self.mul_matrix(&rhs)
+    }
+}
+
+// &P * &Matrix
+impl<T: Clone> ops::Mul<&Matrix<T>> for &Permutation {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.mul_matrix(rhs)
+    }
+}
+
+// Matrix * P
+impl<T: Clone> ops::Mul<Permutation> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Permutation) -> Self::Output {
+        self.mul_permutation(&rhs)
+    }
+}
+
+// &Matrix * &P
+impl<T: Clone> ops::Mul<&Permutation> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Permutation) -> Self::Output {
+        self.mul_permutation(rhs)
+    }
+}
+
+// P * P
+impl ops::Mul for Permutation {
+    type Output = Permutation;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_permutation(&rhs)
+    }
+}
+
+// &P * &P
+impl ops::Mul for &Permutation {
+    type Output = Permutation;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_permutation(rhs)
+    }
+}
+
+///
+/// Conversions into dense matrices
+///
+macro_rules! structured_from_impl(
+    ($($S: ident),* $(,)*) => {$(
+        impl<T: Clone + MatrixZeroOne> From<$S<T>> for Matrix<T> {
+            fn from(s: $S<T>) -> Self {
+                s.to_matrix()
+            }
+        }
+
+        impl<T: Clone + MatrixZeroOne + fmt::Display> fmt::Display for $S<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_matrix(), f)
+            }
+        }
+    )*}
+);
+
+structured_from_impl!(Diagonal, LowerTriangular, UpperTriangular);
+
+impl<T: Clone> From<Symmetric<T>> for Matrix<T> {
+    fn from(s: Symmetric<T>) -> Self {
+        s.to_matrix()
+    }
+}
+
+impl<T: Clone + fmt::Display> fmt::Display for Symmetric<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_matrix(), f)
+    }
+}
+
+impl<T: MatrixZeroOne> From<Permutation> for Matrix<T> {
+    fn from(p: Permutation) -> Self {
+        p.to_matrix()
+    }
+}
+
+impl fmt::Display for Permutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_matrix::<usize>(), f)
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{float::Float, matrix::*, prelude::*};
+use core::{cell::RefCell, ops};
+
+// Elementwise functions that can be recorded on a tape
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Activation {
+    Relu,
+    Sigmoid,
+    Tanh
+}
+
+impl Activation {
+    pub fn apply(&self, x: f64) -> f64 {
+        match self {
+            Activation::Relu => x.max(0.),
+            Activation::Sigmoid => 1. / (1. + Float::exp(-x)),
+            Activation::Tanh => Float::tanh(x)
+        }
+    }
+
+    pub fn derivative(&self, x: f64) -> f64 {
+        match self {
+            Activation::Relu => if x > 0. { 1. } else { 0. },
+            Activation::Sigmoid => {
+                let s = self.apply(x);
+                s * (1. - s)
+            },
+            Activation::Tanh => 1. - Float::powi(Float::tanh(x), 2)
+        }
+    }
+}
+
+// How a node was computed - operands are indices of earlier nodes
+#[derive(Clone, Copy)]
+enum Op {
+    Leaf,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Scale(usize, f64),
+    Transpose(usize),
+    Dot(usize, usize),
+    Norm(usize),
+    Activation(usize, Activation)
+}
+
+struct Node {
+    value: Matrix<f64>,
+    op: Op
+}
+
+/*
+ * Records every operation done on its Vars so that gradients can be propagated back
+ * from a scalar result in one sweep (reverse mode). Nodes are appended in evaluation
+ * order, so walking them backwards visits every node after all of its users.
+ */
+#[derive(Default)]
+pub struct Tape {
+    nodes: RefCell<Vec<Node>>
+}
+
+// Handle to a matrix recorded on a tape, cheap to copy
+#[derive(Clone, Copy)]
+pub struct Var<'t> {
+    tape: &'t Tape,
+    index: usize
+}
+
+// Gradients of one result with respect to every node of the tape
+pub struct Gradients {
+    grads: Vec<Matrix<f64>>
+}
+
+impl Tape {
+    pub fn new() -> Self {
+        Tape::default()
+    }
+
+    // New leaf - something gradients can be asked for, like weights or inputs
+    pub fn var(&self, value: Matrix<f64>) -> Var<'_> {
+        self.push(value, Op::Leaf)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.borrow().is_empty()
+    }
+
+    fn push(&self, value: Matrix<f64>, op: Op) -> Var<'_> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { value, op });
+        Var { tape: self, index: nodes.len() - 1 }
+    }
+
+    fn value(&self, index: usize) -> Matrix<f64> {
+        self.nodes.borrow()[index].value.clone()
+    }
+}
+
+impl<'t> Var<'t> {
+    pub fn value(&self) -> Matrix<f64> {
+        self.tape.value(self.index)
+    }
+
+    fn same_tape(&self, other: &Var<'t>) {
+        if !core::ptr::eq(self.tape, other.tape) {
+            panic!("Vars from different tapes can't be combined!");
+        }
+    }
+
+    pub fn transpose(&self) -> Var<'t> {
+        self.tape.push(self.value().transpose(), Op::Transpose(self.index))
+    }
+
+    // Row and column vectors can be mixed, the result is 1x1
+    pub fn dot(&self, other: &Var<'t>) -> Var<'t> {
+        self.same_tape(other);
+        let value = self.value().dot(&other.value());
+        self.tape.push(Matrix::new_fill(1, 1, value), Op::Dot(self.index, other.index))
+    }
+
+    // Frobenius norm as a 1x1 matrix
+    pub fn norm(&self) -> Var<'t> {
+        self.tape.push(Matrix::new_fill(1, 1, self.value().norm()), Op::Norm(self.index))
+    }
+
+    pub fn activation(&self, activation: Activation) -> Var<'t> {
+        self.tape.push(self.value().map(|&x| activation.apply(x)), Op::Activation(self.index, activation))
+    }
+
+    pub fn relu(&self) -> Var<'t> {
+        self.activation(Activation::Relu)
+    }
+
+    pub fn sigmoid(&self) -> Var<'t> {
+        self.activation(Activation::Sigmoid)
+    }
+
+    pub fn tanh(&self) -> Var<'t> {
+        self.activation(Activation::Tanh)
+    }
+
+    /*
+     * Gradient of this 1x1 result with respect to every node recorded before it.
+     * Each node's gradient is complete once everything after it has been visited.
+     */
+    pub fn backward(&self) -> Gradients {
+        let nodes = self.tape.nodes.borrow();
+        let value = &nodes[self.index].value;
+        if value.height() != 1 || value.width() != 1 {
+            panic!("Backward can only start from a 1x1 value! A=[{},{}]", value.height(), value.width());
+        }
+
+        let mut grads: Vec<Matrix<f64>> = nodes.iter()
+            .map(|node| Matrix::new_fill(node.value.height(), node.value.width(), 0.))
+            .collect();
+        grads[self.index] = Matrix::new_fill(1, 1, 1.);
+
+        let accumulate = |grads: &mut Vec<Matrix<f64>>, index: usize, grad: Matrix<f64>| {
+            grads[index] += grad;
+        };
+
+        for index in (0..=self.index).rev() {
+            let grad = grads[index].clone();
+            match nodes[index].op {
+                Op::Leaf => {},
+                Op::Add(a, b) => {
+                    accumulate(&mut grads, a, grad.clone());
+                    accumulate(&mut grads, b, grad);
+                },
+                Op::Sub(a, b) => {
+                    accumulate(&mut grads, a, grad.clone());
+                    accumulate(&mut grads, b, -grad);
+                },
+
+                // C = AB: dA = dC B^T, dB = A^T dC
+                Op::Mul(a, b) => {
+                    accumulate(&mut grads, a, &grad * &nodes[b].value.clone().transpose());
+                    accumulate(&mut grads, b, &nodes[a].value.clone().transpose() * &grad);
+                },
+                Op::Scale(a, scalar) => accumulate(&mut grads, a, grad * scalar),
+                Op::Transpose(a) => accumulate(&mut grads, a, grad.transpose()),
+
+                // The other operand's values, in this operand's shape in case a row is dotted with a column
+                Op::Dot(a, b) => {
+                    let g = grad.get(0, 0);
+                    let (value_a, value_b) = (&nodes[a].value, &nodes[b].value);
+                    accumulate(&mut grads, a, Matrix::from_row_major(value_a.height(), value_a.width(), value_b.iter().map(|x| x * g)));
+                    accumulate(&mut grads, b, Matrix::from_row_major(value_b.height(), value_b.width(), value_a.iter().map(|x| x * g)));
+                },
+
+                // d|A| = A / |A|, taken as 0 at A = 0
+                Op::Norm(a) => {
+                    let norm = nodes[index].value.get(0, 0);
+                    if norm != 0. {
+                        accumulate(&mut grads, a, &nodes[a].value * (grad.get(0, 0) / norm));
+                    }
+                },
+                Op::Activation(a, activation) => {
+                    let derivative = nodes[a].value.map(|&x| activation.derivative(x));
+                    accumulate(&mut grads, a, grad.hadamard(&derivative));
+                }
+            }
+        }
+
+        Gradients { grads }
+    }
+}
+
+impl Gradients {
+
+    // Gradient with respect to var, same shape as its value
+    pub fn wrt(&self, var: &Var) -> Matrix<f64> {
+        self.grads.get(var.index)
+            .cloned()
+            .unwrap_or_else(|| {
+                let value = var.value();
+                Matrix::new_fill(value.height(), value.width(), 0.)
+            })
+    }
+}
+
+///
+/// Operators
+///
+impl<'t> ops::Add for Var<'t> {
+    type Output = Var<'t>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.same_tape(&rhs);
+        self.tape.push(self.value() + rhs.value(), Op::Add(self.index, rhs.index))
+    }
+}
+
+impl<'t> ops::Sub for Var<'t> {
+    type Output = Var<'t>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.same_tape(&rhs);
+        self.tape.push(self.value() - rhs.value(), Op::Sub(self.index, rhs.index))
+    }
+}
+
+// Matrix product
+impl<'t> ops::Mul for Var<'t> {
+    type Output = Var<'t>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.same_tape(&rhs);
+        self.tape.push(&self.value() * &rhs.value(), Op::Mul(self.index, rhs.index))
+    }
+}
+
+// Var * scalar
+impl<'t> ops::Mul<f64> for Var<'t> {
+    type Output = Var<'t>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.tape.push(self.value() * rhs, Op::Scale(self.index, rhs))
+    }
+}
+
+// Scalar * Var
+impl<'t> ops::Mul<Var<'t>> for f64 {
+    type Output = Var<'t>;
+
+    fn mul(self, rhs: Var<'t>) -> Self::Output {
+        rhs * self
+    }
+}
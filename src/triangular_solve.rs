@@ -0,0 +1,88 @@
+use crate::{matrix::*, scalar::*};
+
+#[derive(Clone, Copy, Default)]
+pub struct TriangularSolveOptions {
+
+    // Diagonal is assumed to be all ones and is never read (e.g. L from LU)
+    pub unit_diagonal: bool,
+
+    // Solve A^T x = b instead of Ax = b, without building the transpose
+    pub transpose: bool
+}
+
+// Read access to the triangle of a square matrix that the solvers use
+pub trait TriangularView<T> {
+    fn triangle_size(&self) -> usize;
+
+    // Only ever called for indices inside the triangle being solved with
+    fn triangle_entry(&self, row_index: usize, col_index: usize) -> &T;
+}
+
+impl<T> TriangularView<T> for Matrix<T> {
+    fn triangle_size(&self) -> usize {
+        if self.height() != self.width() {
+            panic!("Triangular matrix must be square! A=[{},{}]", self.height(), self.width());
+        }
+
+        self.height()
+    }
+
+    fn triangle_entry(&self, row_index: usize, col_index: usize) -> &T {
+        &self[(row_index, col_index)]
+    }
+}
+
+/*
+ * Solves Lx = b (or L^T x = b) for every column of b, only the lower triangle of L is read
+ */
+pub fn solve_lower_triangular<T, A>(l: &A, b: &Matrix<T>, options: TriangularSolveOptions) -> Matrix<T>
+    where T: Field, A: TriangularView<T> + ?Sized
+{
+    substitute(l, b, true, options)
+}
+
+/*
+ * Solves Ux = b (or U^T x = b) for every column of b, only the upper triangle of U is read
+ */
+pub fn solve_upper_triangular<T, A>(u: &A, b: &Matrix<T>, options: TriangularSolveOptions) -> Matrix<T>
+    where T: Field, A: TriangularView<T> + ?Sized
+{
+    substitute(u, b, false, options)
+}
+
+fn substitute<T, A>(a: &A, b: &Matrix<T>, lower: bool, options: TriangularSolveOptions) -> Matrix<T>
+    where T: Field, A: TriangularView<T> + ?Sized
+{
+    let size = a.triangle_size();
+    if size != b.height() {
+        panic!("b must have the same height as A, A=[{size},{size}], b=[{},{}]", b.height(), b.width());
+    }
+
+    // Transposing a lower triangular matrix makes it upper triangular and vice versa
+    let entry = |row: usize, col: usize| {
+        if options.transpose { a.triangle_entry(col, row) } else { a.triangle_entry(row, col) }
+    };
+    let forward = lower != options.transpose;
+
+    let mut x = Matrix::new_fill(b.height(), b.width(), T::zero());
+    for col in 0..b.width() {
+        for step in 0..size {
+
+            // Forward substitution goes top-down, backward substitution bottom-up
+            let row = if forward { step } else { size - 1 - step };
+            let solved = if forward { 0..row } else { (row + 1)..size };
+
+            let mut new_x = b[(row, col)].clone();
+            for i in solved {
+                new_x = new_x.sub(&entry(row, i).mul(&x[(i, col)]));
+            }
+
+            if !options.unit_diagonal {
+                new_x = new_x.div(entry(row, row));
+            }
+
+            x[(row, col)] = new_x;
+        }
+    }
+    x
+}
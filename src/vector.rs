@@ -0,0 +1,448 @@
+use crate::{float::Float, matrix::*, matrix_traits::*, prelude::*, scalar::*};
+use core::{fmt, ops, slice};
+
+/*
+ * Vector without an orientation. Matrix * Vector reads it as a column and Vector * Matrix as a row,
+ * so it never has to be transposed to fit. to_column() and to_row() give the Matrix forms.
+ */
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Vector<T> {
+    data: Vec<T>
+}
+
+impl<T> Vector<T> {
+    pub fn new() -> Self {
+        Vector { data: vec![] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Vector<U> {
+        Vector { data: self.data.iter().map(f).collect() }
+    }
+
+    fn check_same_length<U>(&self, other: &Vector<U>, operation: &str) {
+        if self.len() != other.len() {
+            panic!("{operation} only allowed on vectors of the same length. Vector A length={}, Vector B length={}", self.len(), other.len())
+        }
+    }
+}
+
+impl<T: Clone> Vector<T> {
+    pub fn new_fill(len: usize, value: T) -> Self {
+        Vector { data: vec![value; len] }
+    }
+
+    // n x 1
+    pub fn to_column(&self) -> Matrix<T> {
+        Matrix::from_row_major(self.len(), 1, self.data.iter().cloned())
+    }
+
+    // 1 x n
+    pub fn to_row(&self) -> Matrix<T> {
+        Matrix::from_row_major(1, self.len(), self.data.iter().cloned())
+    }
+
+    // Takes a row or a column vector
+    pub fn from_matrix(a: &Matrix<T>) -> Self {
+        if !a.is_vector() {
+            panic!("Matrix is not a vector! A=[{},{}]", a.height(), a.width());
+        }
+
+        a.iter().cloned().collect()
+    }
+}
+
+///
+/// Products
+///
+impl<T: Semiring> Vector<T> {
+    pub fn zeros(len: usize) -> Self {
+        Vector::new_fill(len, T::zero())
+    }
+
+    // Unit vector along axis
+    pub fn basis(len: usize, axis: usize) -> Self {
+        if axis >= len {
+            panic!("Index out of bounds! [{axis}] / [{len}]");
+        }
+
+        (0..len).map(|index| if index == axis { T::one() } else { T::zero() }).collect()
+    }
+
+    pub fn dot(&self, other: &Vector<T>) -> T {
+        self.check_same_length(other, "Dot operation");
+        self.data.iter().zip(&other.data).fold(T::zero(), |acc, (a, b)| acc.add(&a.mul(b)))
+    }
+
+    // Entry [i, j] is a_i * b_j, the lengths don't have to match
+    pub fn outer(&self, other: &Vector<T>) -> Matrix<T> {
+        Matrix::from_row_major(self.len(), other.len(), self.data.iter().flat_map(|a| other.data.iter().map(move |b| a.mul(b))))
+    }
+}
+
+impl<T: Ring> Vector<T> {
+
+    // Only defined in 3D, perpendicular to both with a right-handed orientation
+    pub fn cross(&self, other: &Vector<T>) -> Vector<T> {
+        if self.len() != 3 || other.len() != 3 {
+            panic!("Cross product is only defined for 3D vectors! Vector A length={}, Vector B length={}", self.len(), other.len());
+        }
+
+        let (a, b) = (&self.data, &other.data);
+        Vector::from([
+            a[1].mul(&b[2]).sub(&a[2].mul(&b[1])),
+            a[2].mul(&b[0]).sub(&a[0].mul(&b[2])),
+            a[0].mul(&b[1]).sub(&a[1].mul(&b[0]))
+        ])
+    }
+}
+
+impl<T: Field> Vector<T> {
+
+    // Component of self along onto, exact for exact types
+    pub fn project_onto(&self, onto: &Vector<T>) -> Vector<T> {
+        let length_squared = onto.dot(onto);
+        if length_squared.is_zero() {
+            panic!("Cannot project onto a zero vector");
+        }
+
+        onto * self.dot(onto).div(&length_squared)
+    }
+
+    // Component of self perpendicular to onto, self = projection + rejection
+    pub fn reject_from(&self, onto: &Vector<T>) -> Vector<T> {
+        self - &self.project_onto(onto)
+    }
+}
+
+///
+/// Lengths and angles
+///
+impl<T: RealField> Vector<T> {
+    pub fn norm(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Vector<T> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            panic!("Cannot normalize a zero vector");
+        }
+
+        self / norm
+    }
+
+    /*
+     * Angle in radians, in [0, pi]. Uses 2 atan2(|u - v|, |u + v|) on the unit vectors instead of
+     * acos of the cosine, which loses precision for nearly parallel vectors. Goes through f64.
+     */
+    pub fn angle_between(&self, other: &Vector<T>) -> T {
+        self.check_same_length(other, "Angle");
+        let (u, v) = (self.normalize(), other.normalize());
+        let to_f64 = |value: T| value.to_f64().expect("Vector element can't be converted to f64");
+        let y = to_f64((&u - &v).norm());
+        let x = to_f64((&u + &v).norm());
+        T::from_f64(2. * Float::atan2(y, x))
+    }
+}
+
+///
+/// Shape-aware products on Matrix - row and column vectors are both accepted
+///
+impl<T: Semiring> Matrix<T> {
+    pub fn outer(&self, other: &Matrix<T>) -> Matrix<T> {
+        Vector::from_matrix(self).outer(&Vector::from_matrix(other))
+    }
+}
+
+impl<T: Ring> Matrix<T> {
+
+    // Result has the same orientation as self
+    pub fn cross(&self, other: &Matrix<T>) -> Matrix<T> {
+        let ret = Vector::from_matrix(self).cross(&Vector::from_matrix(other));
+        if self.width() == 1 { ret.to_column() } else { ret.to_row() }
+    }
+}
+
+///
+/// Conversions
+///
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(data: Vec<T>) -> Self {
+        Vector { data }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T> {
+    fn from(arr: [T; N]) -> Self {
+        Vector { data: Vec::from(arr) }
+    }
+}
+
+// As a column, which is what the solvers expect
+impl<T: Clone> From<Vector<T>> for Matrix<T> {
+    fn from(vector: Vector<T>) -> Self {
+        vector.to_column()
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vector { data: iter.into_iter().collect() }
+    }
+}
+
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Vector<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> ops::Index<usize> for Vector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= self.len() {
+            panic!("Index out of bounds! [{index}] / [{}]", self.len())
+        }
+
+        &self.data[index]
+    }
+}
+
+impl<T> ops::IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= self.len() {
+            panic!("Index out of bounds! [{index}] / [{}]", self.len())
+        }
+
+        &mut self.data[index]
+    }
+}
+
+// Same as a row of a Matrix
+impl<T: fmt::Display> fmt::Display for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, value) in self.data.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?
+            }
+
+            match f.precision() {
+                Some(precision) => write!(f, "{:.*}", precision, value)?,
+                None => write!(f, "{}", value)?
+            }
+        }
+        Ok(())
+    }
+}
+
+///
+/// Operators - u+v and u-v reuse an owned left operand for the result
+///
+macro_rules! vector_op_impl(
+    ($Trait: ident, $method: ident, $AssignTrait: ident, $assign_method: ident, $Bound: ident, $operation: literal) => {
+        impl<T: $Bound> ops::$AssignTrait<&Vector<T>> for Vector<T> {
+            fn $assign_method(&mut self, rhs: &Vector<T>) {
+                self.check_same_length(rhs, $operation);
+                for (value, rhs_value) in self.data.iter_mut().zip(&rhs.data) {
+                    *value = $Bound::$method(value, rhs_value);
+                }
+            }
+        }
+
+        impl<T: $Bound> ops::$AssignTrait for Vector<T> {
+            fn $assign_method(&mut self, rhs: Vector<T>) {
+                ops::$AssignTrait::$assign_method(self, &rhs);
+            }
+        }
+
+        impl<T: $Bound> ops::$Trait<&Vector<T>> for Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(mut self, rhs: &Vector<T>) -> Self::Output {
+                ops::$AssignTrait::$assign_method(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<T: $Bound> ops::$Trait for Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(self, rhs: Vector<T>) -> Self::Output {
+                ops::$Trait::$method(self, &rhs)
+            }
+        }
+
+        impl<T: $Bound> ops::$Trait<Vector<T>> for &Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(self, rhs: Vector<T>) -> Self::Output {
+                ops::$Trait::$method(self.clone(), &rhs)
+            }
+        }
+
+        impl<T: $Bound> ops::$Trait for &Vector<T> {
+            type Output = Vector<T>;
+
+            fn $method(self, rhs: &Vector<T>) -> Self::Output {
+                ops::$Trait::$method(self.clone(), rhs)
+            }
+        }
+    }
+);
+
+vector_op_impl!(Add, add, AddAssign, add_assign, MatrixElem, "Addition");
+vector_op_impl!(Sub, sub, SubAssign, sub_assign, MatrixSub, "Subtraction");
+
+// Vector * Scalar and Vector / Scalar
+impl<T: MatrixElem> ops::Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|value| *value = value.mul(&rhs));
+        self
+    }
+}
+
+impl<T: MatrixElem> ops::Mul<T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|value| value.mul(&rhs))
+    }
+}
+
+impl<T: Field> ops::Div<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn div(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|value| *value = value.div(&rhs));
+        self
+    }
+}
+
+impl<T: Field> ops::Div<T> for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|value| value.div(&rhs))
+    }
+}
+
+impl<T: Ring> ops::Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<T: Ring> ops::Neg for &Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(T::neg)
+    }
+}
+
+// A * v reads v as a column, v * A as a row - both give a Vector
+fn matrix_mul_vector<T: Semiring>(a: &Matrix<T>, v: &Vector<T>) -> Vector<T> {
+    if a.width() != v.len() {
+        panic!("Can't multiply matrix by vector A*v: A.width ({}) != v.len ({})", a.width(), v.len())
+    }
+
+    a.row_iter().map(|row| row.iter().zip(v).fold(T::zero(), |acc, (a, b)| acc.add(&a.mul(b)))).collect()
+}
+
+fn vector_mul_matrix<T: Semiring>(v: &Vector<T>, a: &Matrix<T>) -> Vector<T> {
+    if v.len() != a.height() {
+        panic!("Can't multiply vector by matrix v*A: v.len ({}) != A.height ({})", v.len(), a.height())
+    }
+
+    a.col_iter().map(|col| col.zip(v).fold(T::zero(), |acc, (a, b)| acc.add(&a.mul(b)))).collect()
+}
+
+// Every owned/borrowed combination, the product can't be written into either operand so both are borrowed
+macro_rules! vector_product_impl(
+    ($Lhs: ident, $Rhs: ident, $f: ident) => {
+        impl<T: Semiring> ops::Mul<$Rhs<T>> for $Lhs<T> {
+            type Output = Vector<T>;
+
+            fn mul(self, rhs: $Rhs<T>) -> Self::Output {
+                $f(&self, &rhs)
+            }
+        }
+
+        impl<T: Semiring> ops::Mul<&$Rhs<T>> for $Lhs<T> {
+            type Output = Vector<T>;
+
+            fn mul(self, rhs: &$Rhs<T>) -> Self::Output {
+                $f(&self, rhs)
+            }
+        }
+
+        impl<T: Semiring> ops::Mul<$Rhs<T>> for &$Lhs<T> {
+            type Output = Vector<T>;
+
+            fn mul(self, rhs: $Rhs<T>) -> Self::Output {
+                $f(self, &rhs)
+            }
+        }
+
+        impl<T: Semiring> ops::Mul<&$Rhs<T>> for &$Lhs<T> {
+            type Output = Vector<T>;
+
+            fn mul(self, rhs: &$Rhs<T>) -> Self::Output {
+                $f(self, rhs)
+            }
+        }
+    }
+);
+
+vector_product_impl!(Matrix, Vector, matrix_mul_vector);
+vector_product_impl!(Vector, Matrix, vector_mul_matrix);
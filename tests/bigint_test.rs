@@ -0,0 +1,67 @@
+use mm::{bigint::*, matrix::*, scalar::*};
+
+fn big(s: &str) -> BigInt {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_and_display() {
+    for s in ["0", "7", "-7", "4294967296", "-18446744073709551616", "1000000000000000000000000000001"] {
+        assert_eq!(format!("{}", big(s)), s);
+    }
+    assert_eq!(big("+00012"), BigInt::from(12));
+    assert_eq!(big("-0"), BigInt::from(0));
+    assert!("12a".parse::<BigInt>().is_err());
+    assert!("-".parse::<BigInt>().is_err());
+}
+
+#[test]
+fn arithmetic() {
+    let factorial = (1..=30).fold(BigInt::from(1), |acc, n| acc * BigInt::from(n));
+    assert_eq!(format!("{}", factorial), "265252859812191058636308480000000");
+
+    let a = big("123456789012345678901234567890");
+    let b = big("-987654321098765432109876543210");
+    assert_eq!(format!("{}", a.clone() + b.clone()), "-864197532086419753208641975320");
+    assert_eq!(format!("{}", a.clone() - b.clone()), "1111111110111111111011111111100");
+    assert_eq!(format!("{}", a.clone() * b.clone()), "-121932631137021795226185032733622923332237463801111263526900");
+    assert_eq!(BigInt::from(2).pow(100), big("1267650600228229401496703205376"));
+    assert!(b < a);
+    assert!(big("-5") < big("-4"));
+}
+
+#[test]
+fn division_truncates_towards_zero() {
+    let a = big("1267650600228229401496703205377");
+    let b = big("18446744073709551616");
+    let (q, r) = a.div_rem(&b);
+    assert_eq!(q, big("68719476736"));
+    assert_eq!(r, BigInt::from(1));
+
+    assert_eq!(BigInt::from(-7).div_rem(&BigInt::from(2)), (BigInt::from(-3), BigInt::from(-1)));
+    assert_eq!(BigInt::from(7).div_rem(&BigInt::from(-2)), (BigInt::from(-3), BigInt::from(1)));
+    assert_eq!(big("-600000000000000000000").gcd(&big("400000000000000000000")), big("200000000000000000000"));
+}
+
+#[test]
+#[should_panic(expected = "Division of a BigInt by 0")]
+fn division_by_zero() {
+    let _ = BigInt::from(1) / BigInt::from(0);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+    assert_eq!(BigInt::from(i64::MAX).to_i64(), Some(i64::MAX));
+    assert_eq!((BigInt::from(i64::MAX) + BigInt::from(1)).to_i64(), None);
+    assert_eq!(BigInt::from(u64::MAX).bits(), 64);
+    assert_eq!(BigInt::from(-3).signum(), -1);
+}
+
+#[test]
+fn matrix_of_bigints() {
+    let a: Matrix<BigInt> = Matrix::from([[big("100000000000000000000"), BigInt::from(1)], [BigInt::from(1), big("100000000000000000000")]]);
+    let squared = &a * &a;
+    assert_eq!(format!("{}", squared),
+        "10000000000000000000000000000000000000001,200000000000000000000\n200000000000000000000,10000000000000000000000000000000000000001");
+}
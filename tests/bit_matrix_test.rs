@@ -0,0 +1,110 @@
+use mm::bit_matrix::*;
+
+// Parity-check matrix of the Hamming(7,4) code, column i is i+1 in binary
+fn hamming() -> BitMatrix {
+    BitMatrix::from(
+        [[1,0,1,0,1,0,1],
+         [0,1,1,0,0,1,1],
+         [0,0,0,1,1,1,1]])
+}
+
+#[test]
+fn get_set() {
+    let mut a = BitMatrix::new(2, 130);
+    a.set(1, 129, true);
+    a.set(0, 64, true);
+    a.set(0, 64, false);
+    assert!(a.get(1, 129));
+    assert!(!a.get(0, 64));
+    assert_eq!(a.count_ones(), 1);
+    assert!(a.transpose().get(129, 1));
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds! [0, 3] / [2, 3]")]
+fn get_out_of_bounds() {
+    BitMatrix::new(2, 3).get(0, 3);
+}
+
+#[test]
+fn add_and_mul() {
+    let a = BitMatrix::from([[1,1],[0,1]]);
+    let b = BitMatrix::from([[1,0],[1,1]]);
+    assert_eq!(format!("{}", &a + &b), "0,1\n1,0");
+    assert_eq!(format!("{}", &a * &b), "0,1\n1,1");
+    assert_eq!(&a * &a, BitMatrix::identity(2));
+}
+
+#[test]
+fn rref_and_rank() {
+    let h = hamming();
+    assert_eq!(h.rank(), 3);
+    assert_eq!(format!("{}", h.rref()), "1,0,1,0,1,0,1\n0,1,1,0,0,1,1\n0,0,0,1,1,1,1");
+
+    let a = BitMatrix::from([[1,1,0],[0,1,1],[1,0,1]]);
+    assert_eq!(a.rank(), 2);
+    assert_eq!(format!("{}", a.rref()), "1,0,1\n0,1,1\n0,0,0");
+}
+
+#[test]
+fn inverse() {
+    let a = BitMatrix::from(
+        [[1,1,0],
+         [0,1,1],
+         [1,1,1]]);
+    let inv_a = a.inv();
+    assert_eq!(format!("{}", inv_a), "0,1,1\n1,1,1\n1,0,1");
+    assert_eq!(&a * &inv_a, BitMatrix::identity(3));
+}
+
+#[test]
+#[should_panic(expected = "Cannot inverse a singular matrix!")]
+fn inverse_singular() {
+    BitMatrix::from([[1,1],[1,1]]).inv();
+}
+
+#[test]
+fn null_space_is_the_code() {
+    let h = hamming();
+    let generator = h.null_space();
+    assert_eq!(generator.width(), 4);
+    assert_eq!(&h * &generator, BitMatrix::new(3, 4));
+    assert_eq!(generator.transpose().rank(), 4);
+}
+
+#[test]
+fn syndrome_decoding() {
+    let h = hamming();
+    let generator = h.null_space();
+    let mut codeword = BitMatrix::new(7, 1);
+    for row in 0..7 {
+        codeword.set(row, 0, generator.get(row, 2));
+    }
+    assert_eq!(&h * &codeword, BitMatrix::new(3, 1));
+
+    // Flip bit 4, the syndrome spells out 5 in binary
+    let mut received = codeword.clone();
+    received.set(4, 0, !received.get(4, 0));
+    let syndrome = &h * &received;
+    assert_eq!(format!("{}", syndrome.transpose()), "1,0,1");
+
+    // Any error pattern with that syndrome, plus the kernel, gives back the codeword
+    let error = h.solve(&syndrome).unwrap();
+    assert_eq!(&h * &error, syndrome);
+    assert_eq!(&h * &(&received + &error), BitMatrix::new(3, 1));
+}
+
+#[test]
+fn solve_inconsistent() {
+    let a = BitMatrix::from([[1,1],[1,1]]);
+    assert!(a.solve(&BitMatrix::from([[1],[0]])).is_none());
+    assert_eq!(format!("{}", a.solve(&BitMatrix::from([[1],[1]])).unwrap()), "1\n0");
+}
+
+#[test]
+fn matrix_conversion() {
+    let a = BitMatrix::from([[1,0,1],[0,1,1]]);
+    let m = a.to_matrix();
+    assert_eq!(format!("{}", m), "1,0,1\n0,1,1");
+    assert_eq!(BitMatrix::from_matrix(&m), a);
+}
@@ -1,15 +1,15 @@
-use mm::matrix::Matrix;
+use mm::{matrix::Matrix, scalar::RealField};
 
-pub fn fix_zeroes(mut a: Matrix<f64>) -> Matrix<f64>
+pub fn fix_zeroes<T: RealField>(mut a: Matrix<T>) -> Matrix<T>
 {
-    let eps = 0.0000001f64;
+    let eps = T::from_f64(0.0000001f64);
     for row in 0..a.height() {
         for col in 0..a.width() {
             if a.get(row,col).abs() < eps {
-                a.set(row, col, 0.);
+                a.set(row, col, T::zero());
             }
         }
     }
 
     a
-}
\ No newline at end of file
+}
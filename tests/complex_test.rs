@@ -0,0 +1,82 @@
+use mm::{complex::*, lu_decomposition::*, matrix::*, qr_factorization::*};
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+#[test]
+fn arithmetic() {
+    let a = c(1., 2.);
+    let b = c(3., -1.);
+    assert_eq!(a + b, c(4., 1.));
+    assert_eq!(a - b, c(-2., 3.));
+    assert_eq!(a * b, c(5., 5.));
+    assert_eq!(format!("{:.2}", a / b), "0.10+0.70i");
+    assert_eq!(Complex::<f64>::i() * Complex::i(), c(-1., 0.));
+    assert_eq!(a.conj(), c(1., -2.));
+    assert_eq!(c(3., 4.).abs(), 5.);
+}
+
+#[test]
+fn display() {
+    let m = Matrix::from([[c(1., 2.), c(0.5, -1.)]]);
+    assert_eq!(format!("{}", m), "1+2i,0.5-1i");
+    assert_eq!(format!("{:.1}", m), "1.0+2.0i,0.5-1.0i");
+}
+
+#[test]
+fn adjoint_and_hermitian() {
+    let m = Matrix::from(
+        [[c(2., 0.), c(1., 1.)],
+         [c(1., -1.), c(3., 0.)]]);
+    assert!(m.is_hermitian());
+    assert_eq!(format!("{}", m.clone().adjoint()), format!("{}", m));
+
+    let n = Matrix::from([[c(1., 1.), c(2., 0.)]]);
+    assert!(!n.is_hermitian());
+    assert_eq!(format!("{}", n.adjoint()), "1-1i\n2+0i");
+
+    // Real symmetric matrices are Hermitian
+    assert!(Matrix::from([[1.,2.],[2.,1.]]).is_hermitian());
+}
+
+#[test]
+fn frobenius_norm() {
+    let m = Matrix::from([[c(3., 4.), c(0., 0.)], [c(1., 0.), c(0., 2.)]]);
+    assert_eq!(format!("{:.6}", m.frobenius_norm()), format!("{:.6}", 30f64.sqrt()));
+    assert_eq!(format!("{:.6}", m.norm()), format!("{:.6}", 30f64.sqrt()));
+}
+
+#[test]
+fn complex_lu_solve() {
+    let a = Matrix::from(
+        [[c(1., 1.), c(2., 0.)],
+         [c(0., -1.), c(1., 2.)]]);
+    let x = Matrix::from([[c(1., 0.)], [c(2., -1.)]]);
+    let b = &a * &x;
+
+    let res = lu_solve(&lu_gauss(&a), &b);
+    assert!((res - x).frobenius_norm() < 1e-12);
+
+    let inv_a = inv(&a);
+    assert!((&a * &inv_a - Matrix::identity(2)).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn complex_qr() {
+    let a = Matrix::from(
+        [[c(1., 1.), c(2., 0.)],
+         [c(0., -1.), c(1., 2.)],
+         [c(3., 0.), c(0., 1.)]]);
+    let res = qr(&a);
+    let q = q_from_reflectors(&res.h);
+
+    // Q is unitary and QR = A
+    assert!((&q.clone().adjoint() * &q - Matrix::identity(3)).frobenius_norm() < 1e-12);
+    assert!((&q * &res.r - a).frobenius_norm() < 1e-12);
+
+    // R is upper triangular
+    assert!(res.r.get(1, 0).abs() < 1e-12);
+    assert!(res.r.get(2, 0).abs() < 1e-12);
+    assert!(res.r.get(2, 1).abs() < 1e-12);
+}
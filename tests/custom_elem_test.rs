@@ -0,0 +1,124 @@
+use mm::{lu_decomposition::*, matrix::*, matrix_traits::*, rref::*, scalar::*};
+use std::fmt;
+
+/*
+ * Example of a user defined element type - integers modulo 7.
+ *
+ * MatrixElem is enough for + and * of matrices, MatrixSub adds -, MatrixZeroOne adds identities
+ * (making it a Semiring, so Matrix::pow works), and Ring/Field unlock the decompositions (lu_gauss, inv, rref, ...).
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Mod7(u8);
+
+impl MatrixElem for Mod7 {
+    fn add(&self, rhs: &Self) -> Self {
+        Mod7((self.0 + rhs.0) % 7)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        Mod7((self.0 * rhs.0) % 7)
+    }
+
+    // to_f64 is optional, Matrix::norm is the only thing needing it
+}
+
+impl MatrixSub for Mod7 {
+    fn sub(&self, rhs: &Self) -> Self {
+        Mod7((self.0 + 7 - rhs.0) % 7)
+    }
+}
+
+impl MatrixZeroOne for Mod7 {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl MatrixConstZeroOne for Mod7 {
+    const ZERO: Self = Mod7(0);
+    const ONE: Self = Mod7(1);
+}
+
+impl Ring for Mod7 {}
+
+impl Field for Mod7 {
+
+    // Any non-zero pivot is exact, so magnitude only tells zero apart from the rest
+    type Magnitude = bool;
+
+    fn div(&self, rhs: &Self) -> Self {
+        // Fermat's little theorem: x^-1 = x^5 mod 7
+        let mut inverse = Mod7(1);
+        for _ in 0..5 {
+            inverse = inverse.mul(rhs);
+        }
+        self.mul(&inverse)
+    }
+
+    fn magnitude(&self) -> bool {
+        !self.is_zero()
+    }
+
+    fn negligible(_: &bool) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for Mod7 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn mod7<const M: usize, const N: usize>(arr: [[u8; M]; N]) -> Matrix<Mod7> {
+    Matrix::from(arr.map(|row| row.map(Mod7)))
+}
+
+#[test]
+fn arithmetic() {
+    let a = mod7([[1,2],[3,4]]);
+    let b = mod7([[5,6],[0,1]]);
+    assert_eq!(format!("{}", a.clone() + b.clone()), "6,1\n3,5");
+    assert_eq!(format!("{}", a.clone() - b.clone()), "3,3\n3,3");
+    assert_eq!(format!("{}", a * b), "5,1\n1,1");
+}
+
+#[test]
+fn identity() {
+    let i: Matrix<Mod7> = Matrix::identity(2);
+    assert!(i.is_identity());
+}
+
+#[test]
+fn inverse() {
+    let a = mod7([[1,2],[3,4]]);
+    let inv_a = inv(&a);
+    assert_eq!(format!("{}", inv_a), "5,1\n5,3");
+    assert!((a * inv_a).is_identity());
+}
+
+#[test]
+fn rank_mod7() {
+    // Second row is 3x the first one mod 7
+    let a = mod7([[1,2,3],[3,6,2]]);
+    assert_eq!(rank(&a), 1);
+    assert_eq!(format!("{}", rref(&a)), "1,2,3\n0,0,0");
+}
+
+#[test]
+#[should_panic(expected = "Matrix element can't be converted to f64")]
+fn norm_needs_to_f64() {
+    let _ = mod7([[1]]).norm();
+}
@@ -0,0 +1,92 @@
+use mm::{dual::*, lu_decomposition::*, matrix::*, qr_factorization::*, scalar::*};
+
+use crate::common::fix_zeroes;
+mod common;
+
+#[test]
+fn derivative_of_polynomial() {
+    // x^2 + 3x at 2
+    let x = Dual::variable(2.);
+    let y = x * x + Dual::constant(3.) * x;
+    assert_eq!(y, Dual::new(10., 7.));
+    assert_eq!(x.powi(-2), Dual::new(0.25, -0.25));
+    assert_eq!(Dual::variable(4.).sqrt(), Dual::new(2., 0.25));
+    assert_eq!(format!("{:.1}", Dual::new(1., -2.)), "1.0-2.0e");
+}
+
+#[test]
+fn jacobian_of_linear_map() {
+    let a = Matrix::from([[1.,2.],[3.,4.],[5.,6.]]);
+    let x = Matrix::from([[1.],[-1.]]);
+    let dual_a = Matrix::from([[1.,2.],[3.,4.],[5.,6.]].map(|row| row.map(Dual::constant)));
+    let j = jacobian(|v| &dual_a * v, &x);
+    assert_eq!(format!("{:.2}", j), format!("{:.2}", a));
+}
+
+#[test]
+fn jacobian_through_inverse() {
+    // d/dt inv(A + tB) = -inv(A) B inv(A) at t = 0
+    let a = Matrix::from([[4.,1.],[2.,3.]]);
+    let b = Matrix::from([[1.,0.],[1.,2.]]);
+    let j = jacobian(|t| {
+        let t = t.get(0, 0);
+        let mut m = Matrix::new_fill(2, 2, Dual::constant(0.));
+        for row in 0..2 {
+            for col in 0..2 {
+                m.set(row, col, Dual::constant(a.get(row, col)) + t * Dual::constant(b.get(row, col)));
+            }
+        }
+        inv(&m)
+    }, &Matrix::from([[0.]]));
+
+    let inv_a = inv(&a);
+    let expected = &(&inv_a * &b) * &inv_a * -1.;
+    assert_eq!(format!("{:.6}", j), format!("{:.6}", Matrix::from(
+        [[expected.get(0, 0)], [expected.get(0, 1)], [expected.get(1, 0)], [expected.get(1, 1)]])));
+}
+
+#[test]
+fn gradient_of_determinant() {
+    // d det(X) / dX = det(X) inv(X)^T
+    let x = Matrix::from([[2.,1.,0.],[1.,3.,1.],[0.,1.,4.]]);
+    let g = gradient(det, &x);
+    let expected = inv(&x).transpose() * det(&x);
+    assert_eq!(format!("{:.6}", g), format!("{:.6}", expected));
+}
+
+#[test]
+fn gradient_through_qr() {
+    // |det(A)| = product of |r_ii|, so its gradient matches the LU one up to sign
+    let x = Matrix::from([[2.,1.],[1.,3.]]);
+    let g = gradient(|m| {
+        let r = qr(m).r;
+        r.get(0, 0) * r.get(1, 1)
+    }, &x);
+    let expected = inv(&x).transpose() * det(&x);
+    let sign = if g.get(0, 0) * expected.get(0, 0) < 0. { -1. } else { 1. };
+    assert_eq!(format!("{:.6}", fix_zeroes(g * sign)), format!("{:.6}", expected));
+
+    // Frobenius norm is differentiable away from 0: d|X| / dX = X / |X|
+    let n = gradient(|m| m.frobenius_norm(), &x);
+    assert_eq!(format!("{:.6}", n), format!("{:.6}", x.clone() * (1. / x.frobenius_norm())));
+}
+
+#[test]
+fn hessian_of_polynomial() {
+    // x^2 y + y^3 at (1, 2)
+    let h = hessian(|v| {
+        let (x, y) = (v.get(0, 0), v.get(1, 0));
+        x * x * y + y * y * y
+    }, &Matrix::from([[1.],[2.]]));
+    assert_eq!(format!("{:.2}", h), "4.00,2.00\n2.00,12.00");
+}
+
+#[test]
+fn hessian_of_norm() {
+    // |v| at (3, 4) has Hessian (I - u u^T) / |v|
+    let h = hessian(|v| v.frobenius_norm(), &Matrix::from([[3., 4.]]));
+    assert_eq!(format!("{:.3}", h), "0.128,-0.096\n-0.096,0.072");
+
+    let r = HyperDual::new(4., 1., 1., 0.).recip();
+    assert_eq!(r, HyperDual::new(0.25, -0.0625, -0.0625, 0.03125));
+}
@@ -0,0 +1,98 @@
+use mm::{matrix::*, rational::*};
+
+#[test]
+fn map_and_zip() {
+    let a: Matrix<i32> = Matrix::from([[1,-2],[3,-4]]);
+    assert_eq!(format!("{}", a.map(|x| x * x)), "1,4\n9,16");
+    assert_eq!(format!("{}", a.map(|&x| x > 0)), "true,false\ntrue,false");
+
+    let mut b = a.clone();
+    b.map_inplace(|x| *x = x.abs());
+    assert_eq!(format!("{}", b), "1,2\n3,4");
+
+    let names = Matrix::from([["a","b"],["c","d"]]);
+    assert_eq!(format!("{}", a.zip_map(&names, |x, name| format!("{x}{name}"))), "1a,-2b\n3c,-4d");
+}
+
+#[test]
+#[should_panic(expected = "Can't zip matrices of different sizes: A=[2,2], B=[1,2]")]
+fn zip_different_sizes() {
+    let a = Matrix::new_fill(2, 2, 1);
+    a.zip_map(&Matrix::new_fill(1, 2, 1), |x, y| x + y);
+}
+
+#[test]
+fn hadamard_and_division() {
+    let a = Matrix::from([[1.,2.],[3.,4.]]);
+    let b = Matrix::from([[2.,0.5],[-1.,4.]]);
+    assert_eq!(format!("{}", a.hadamard(&b)), "2,1\n-3,16");
+    assert_eq!(format!("{}", a.elementwise_div(&b)), "0.5,4\n-3,1");
+
+    let r = Matrix::from([[Ratio::new(1, 2), Ratio::from(3)]]);
+    let s = Matrix::from([[Ratio::new(3, 4), Ratio::from(6)]]);
+    assert_eq!(format!("{}", r.elementwise_div(&s)), "2/3,1/2");
+}
+
+#[test]
+fn broadcasting() {
+    let a = Matrix::from([[1.,2.,3.],[4.,5.,6.]]);
+    let row = Matrix::from([[10.,20.,30.]]);
+    let col = Matrix::from([[1.],[2.]]);
+
+    assert_eq!(format!("{}", a.broadcast_add(&row)), "11,22,33\n14,25,36");
+    assert_eq!(format!("{}", a.broadcast_sub(&col)), "0,1,2\n2,3,4");
+    assert_eq!(format!("{}", a.broadcast_mul(&col)), "1,2,3\n8,10,12");
+    assert_eq!(format!("{}", a.broadcast_div(&Matrix::from([[2.]]))), "0.5,1,1.5\n2,2.5,3");
+
+    // Either side can be the smaller one, a column with a row gives every combination
+    assert_eq!(format!("{}", row.broadcast_sub(&a)), "9,18,27\n6,15,24");
+    assert_eq!(format!("{}", col.broadcast_mul(&row)), "10,20,30\n20,40,60");
+    assert_eq!(format!("{}", a.broadcast_add(&a)), "2,4,6\n8,10,12");
+}
+
+#[test]
+fn broadcasting_operators() {
+    let a = Matrix::from([[1.,2.,3.],[4.,5.,6.]]);
+    let row = Matrix::from([[10.,20.,30.]]);
+    let col = Matrix::from([[1.],[2.]]);
+
+    assert_eq!(format!("{}", &a + &row), "11,22,33\n14,25,36");
+    assert_eq!(format!("{}", a.clone() - &col), "0,1,2\n2,3,4");
+    assert_eq!(format!("{}", &row - a.clone()), "9,18,27\n6,15,24");
+    assert_eq!(format!("{}", &a / &col), "1,2,3\n2,2.5,3");
+    assert_eq!(format!("{}", &a / &a), "1,1,1\n1,1,1");
+    assert_eq!(format!("{}", col.clone() + row.clone()), "11,21,31\n12,22,32");
+
+    let mut b = a.clone();
+    b -= &row;
+    b /= Matrix::from([[-1.]]);
+    assert_eq!(format!("{}", b), "9,18,27\n6,15,24");
+}
+
+#[test]
+#[should_panic(expected = "Can't add matrices A+=B: B=[2,3] doesn't broadcast to A=[1,3]")]
+fn broadcasting_assign_grows() {
+    let mut row = Matrix::new_fill(1, 3, 1);
+    row += Matrix::new_fill(2, 3, 1);
+}
+
+#[test]
+#[should_panic(expected = "Can't divide matrices A/B: A.width (3) != B.width (2)")]
+fn broadcasting_operator_incompatible() {
+    let _ = Matrix::new_fill(2, 3, 1.) / Matrix::new_fill(1, 2, 1.);
+}
+
+#[test]
+fn broadcast_normalize_rows() {
+
+    // Dividing each row by its sum, the usual softmax-style normalization
+    let a = Matrix::from([[1.,3.],[2.,2.]]);
+    let sums = Matrix::from([[4.],[4.]]);
+    assert_eq!(format!("{:.2}", a.broadcast_div(&sums)), "0.25,0.75\n0.50,0.50");
+}
+
+#[test]
+#[should_panic(expected = "Can't broadcast matrices: A=[2,3], B=[2,2]")]
+fn broadcast_incompatible() {
+    Matrix::new_fill(2, 3, 1).broadcast_add(&Matrix::new_fill(2, 2, 1));
+}
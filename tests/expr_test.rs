@@ -0,0 +1,141 @@
+use mm::{cofactor::*, expr::*, lu_decomposition::*, matrix::*};
+
+fn x() -> Expr {
+    Expr::var("x")
+}
+
+fn y() -> Expr {
+    Expr::var("y")
+}
+
+fn symbols<const M: usize, const N: usize>(arr: [[&str; M]; N]) -> Matrix<Expr> {
+    Matrix::from(arr.map(|row| row.map(Expr::var)))
+}
+
+#[test]
+fn precedence() {
+    let (a, b, c) = (Expr::var("a"), Expr::var("b"), Expr::var("c"));
+    assert_eq!(format!("{}", (a.clone() + b.clone()) * c.clone()), "(a+b)*c");
+    assert_eq!(format!("{}", a.clone() + b.clone() * c.clone()), "a+b*c");
+    assert_eq!(format!("{}", a.clone() - (b.clone() + c.clone())), "a-b-c");
+    assert_eq!(format!("{}", (a.clone() + b.clone()).powi(2)), "(a+b)^2");
+    assert_eq!(format!("{}", a.clone() / (b.clone() + c.clone())), "a/(b+c)");
+    assert_eq!(format!("{}", a.clone() / (b.clone() * c.clone())), "a/(b*c)");
+    assert_eq!(format!("{}", -(a.clone() * b.clone())), "-a*b");
+    assert_eq!(format!("{}", Expr::constant(1.) / a), "1/a");
+    assert_eq!(format!("{}", Expr::constant(-2.).powi(3)), "-8");
+}
+
+#[test]
+fn simplification() {
+    assert_eq!(format!("{}", x() + x()), "2*x");
+    assert_eq!(format!("{}", x() * x() * Expr::constant(3.)), "3*x^2");
+    assert_eq!(x() - x(), Expr::constant(0.));
+    assert_eq!(x() * x().powi(-1), Expr::constant(1.));
+    assert_eq!(format!("{}", Expr::constant(2.) * Expr::constant(3.) + x()), "x+6");
+    assert_eq!(format!("{}", x() * y() + y() * x() * Expr::constant(2.)), "3*x*y");
+    assert_eq!(format!("{}", (x() * y()).powi(2)), "x^2*y^2");
+    assert_eq!(format!("{}", x().powi(2).powi(3)), "x^6");
+    assert_eq!(format!("{}", (x() + y()).powi(2).expand()), "2*x*y+x^2+y^2");
+    assert_eq!(x() * Expr::constant(0.), Expr::constant(0.));
+
+    // Unsimplified trees are collected too
+    let raw = Expr::Add(vec![x(), Expr::Mul(vec![Expr::constant(2.), x()]), Expr::constant(1.), Expr::constant(-1.)]);
+    assert_eq!(format!("{}", raw), "x+2*x+1-1");
+    assert_eq!(format!("{}", raw.simplify()), "3*x");
+}
+
+#[test]
+fn matrix_product() {
+    let a = symbols([["a","b"],["c","d"]]);
+    let b = Matrix::from([[x() + y(), Expr::constant(1.)], [Expr::constant(0.), x()]]);
+    assert_eq!(format!("{}", &a * &b), "a*(x+y),a+b*x\nc*(x+y),c+d*x");
+    assert_eq!(format!("{}", &a * &a), "a^2+b*c,a*b+b*d\na*c+c*d,b*c+d^2");
+}
+
+#[test]
+fn substitution() {
+    let e = x() * x() + Expr::constant(2.) * y();
+    assert_eq!(format!("{}", e.subs("x", &(y() + Expr::constant(1.)))), "(y+1)^2+2*y");
+    assert_eq!(e.subs("x", &Expr::constant(3.)).subs("y", &Expr::constant(1.)), Expr::constant(11.));
+    assert_eq!(e.eval(&[("x", 3.), ("y", 1.)]), 11.);
+    assert_eq!(e.variables(), vec!["x".to_string(), "y".to_string()]);
+
+    let m = Matrix::from([[x(), y()], [x() * y(), Expr::constant(1.)]]);
+    assert_eq!(format!("{:.1}", m.eval(&[("x", 2.), ("y", 0.5)])), "2.0,0.5\n1.0,1.0");
+}
+
+#[test]
+#[should_panic(expected = "Variable y has no value")]
+fn eval_missing_variable() {
+    (x() + y()).eval(&[("x", 1.)]);
+}
+
+#[test]
+fn symbolic_det_and_inverse() {
+    let a = symbols([["a","b"],["c","d"]]);
+    assert_eq!(format!("{}", cofactor_det(&a)), "a*d-b*c");
+    assert_eq!(format!("{}", cofactor_inv(&a)), "d/(a*d-b*c),-b/(a*d-b*c)\n-c/(a*d-b*c),a/(a*d-b*c)");
+
+    let b = symbols([["a","b","c"],["d","e","f"],["g","h","i"]]);
+    assert_eq!(format!("{}", cofactor_det(&b)), "a*(e*i-f*h)-b*(d*i-f*g)+c*(d*h-e*g)");
+    assert_eq!(format!("{}", cofactor_det(&b).expand()), "a*e*i-a*f*h-b*d*i+b*f*g+c*d*h-c*e*g");
+
+    // Numeric check against LU
+    let values = [("a", 2.), ("b", 1.), ("c", 0.), ("d", 1.), ("e", 3.), ("f", 1.), ("g", 0.), ("h", 1.), ("i", 4.)];
+    let numeric = b.eval(&values);
+    assert_eq!(format!("{:.6}", cofactor_inv(&b).eval(&values)), format!("{:.6}", inv(&numeric)));
+    assert_eq!(format!("{:.6}", cofactor_det(&b).eval(&values)), format!("{:.6}", det(&numeric)));
+}
+
+#[test]
+fn cofactor_over_integers() {
+    let a: Matrix<i64> = Matrix::from([[2,1,1],[1,3,2],[1,0,0]]);
+    assert_eq!(cofactor_det(&a), -1);
+    assert_eq!(format!("{}", adjugate(&a)), "0,0,-1\n2,-1,-3\n-3,1,5");
+}
+
+#[test]
+#[should_panic(expected = "Division of an expression by 0")]
+fn division_by_zero() {
+    let _ = x() / (y() - y());
+}
+
+#[test]
+fn differentiation() {
+    assert_eq!(format!("{}", (x() * x() * y() + Expr::constant(3.) * x()).diff("x")), "2*x*y+3");
+    assert_eq!(format!("{}", (x() + y()).powi(3).diff("x")), "3*(x+y)^2");
+    assert_eq!(format!("{}", (Expr::constant(1.) / x()).diff("x")), "-1/x^2");
+    assert_eq!(format!("{}", (x() * y()).diff("z")), "0");
+
+    let m = Matrix::from([[x() * y(), x().powi(2)], [y(), Expr::constant(5.)]]);
+    assert_eq!(format!("{}", m.diff("x")), "y,2*x\n0,0");
+}
+
+#[test]
+fn jacobian_and_hessian() {
+
+    // Polar to cartesian, c and s stand in for the cosine and sine
+    let (r, c, s) = (Expr::var("r"), Expr::var("c"), Expr::var("s"));
+    let f = Matrix::from([[r.clone() * c.clone()], [r.clone() * s.clone()], [r.clone() * r.clone() * c * s]]);
+    assert_eq!(format!("{}", f.jacobian(&["r", "c", "s"])), "c,r,0\ns,0,r\n2*c*r*s,r^2*s,c*r^2");
+
+    // f(x, y) = x^3 + 2xy^2 - y
+    let f = x().powi(3) + Expr::constant(2.) * x() * y() * y() - y();
+    assert_eq!(format!("{}", f.gradient(&["x", "y"])), "3*x^2+2*y^2\n4*x*y-1");
+
+    let h = f.hessian(&["x", "y"]);
+    assert_eq!(format!("{}", h), "6*x,4*y\n4*y,4*x");
+    assert_eq!(format!("{:.1}", h.eval(&[("x", 1.), ("y", -2.)])), "6.0,-8.0\n-8.0,4.0");
+}
+
+#[test]
+fn unsimplified_operands() {
+
+    // Built from the variants, 2*3 is never simplified on its own
+    let six = Expr::Mul(vec![Expr::Const(2.), Expr::Const(3.)]);
+    assert_eq!(format!("{}", six.clone() + x()), "x+6");
+    assert_eq!(format!("{}", x() + six.clone()), "x+6");
+    assert_eq!(format!("{}", six.clone() * x()), "6*x");
+    assert_eq!(format!("{}", Expr::Add(vec![six, Expr::Mul(vec![Expr::Const(2.), x()])]) - x()), "x+6");
+}
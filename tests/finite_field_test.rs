@@ -0,0 +1,77 @@
+use mm::{finite_field::*, lu_decomposition::*, matrix::*, rref::*, scalar::*};
+
+type F7 = Mod<7>;
+
+fn gf7<const M: usize, const N: usize>(arr: [[i64; M]; N]) -> Matrix<F7> {
+    Matrix::from(arr.map(|row| row.map(F7::from)))
+}
+
+#[test]
+fn arithmetic() {
+    let a = F7::new(3);
+    assert_eq!(a + F7::new(5), F7::new(1));
+    assert_eq!(a - F7::new(5), F7::new(5));
+    assert_eq!(a * F7::new(5), F7::new(1));
+    assert_eq!(a.recip(), F7::new(5));
+    assert_eq!(F7::new(2) / a, F7::new(3));
+    assert_eq!(-a, F7::new(4));
+    assert_eq!(F7::from(-1i64), F7::new(6));
+    assert_eq!(F7::new(15).value(), 1);
+    assert_eq!(a.pow(6), F7::new(1));
+    assert_eq!(F7::default(), F7::new(0));
+}
+
+#[test]
+fn large_prime() {
+    type F = Mod<18446744073709551557>;
+    let a = F::new(18446744073709551556);
+    assert_eq!(a * a, F::new(1));
+    assert_eq!(a + a, F::new(18446744073709551555));
+    assert_eq!(F::new(12345).recip() * F::new(12345), F::new(1));
+}
+
+#[test]
+#[should_panic(expected = "Division by 0 in GF(7)")]
+fn division_by_zero() {
+    let _ = F7::new(1) / F7::new(0);
+}
+
+#[test]
+fn inverse_and_det() {
+    let a = gf7(
+        [[1,2,3],
+         [0,1,4],
+         [5,6,0]]);
+    let inv_a = inv(&a);
+    assert_eq!(format!("{}", inv_a), "4,4,5\n6,6,3\n2,4,1");
+    assert!((&a * &inv_a).is_identity());
+    assert_eq!(det(&a), F7::new(1));
+}
+
+#[test]
+fn rref_and_rank() {
+    // Rank 3 over the rationals, but row 3 = 6 * (row 1 + row 2) mod 7
+    let a = gf7(
+        [[1,2,3],
+         [2,1,1],
+         [4,4,3]]);
+    assert_eq!(format!("{}", rref(&a)), "1,0,2\n0,1,4\n0,0,0");
+    assert_eq!(rank(&a), 2);
+}
+
+#[test]
+fn null_space_and_solve() {
+    let a = gf7(
+        [[1,2,3],
+         [2,1,1],
+         [4,4,3]]);
+    let kernel = null_space(&a);
+    assert_eq!(format!("{}", kernel), "5\n3\n1");
+    assert_eq!(format!("{}", &a * &kernel), "0\n0\n0");
+
+    let b = gf7([[1],[2],[4]]);
+    let x = solve(&a, &b).unwrap();
+    assert_eq!(format!("{}", &a * &x), "1\n2\n4");
+
+    assert!(solve(&a, &gf7([[1],[2],[3]])).is_none());
+}
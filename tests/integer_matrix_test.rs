@@ -0,0 +1,120 @@
+use mm::{bigint::*, integer_matrix::*, matrix::*, matrix_traits::*};
+
+#[test]
+fn hermite_normal_form_simple() {
+    let a: Matrix<i64> = Matrix::from([[2,4],[6,8]]);
+    let hnf = hermite_normal_form(&a);
+    assert_eq!(format!("{}", hnf.h), "2,0\n0,4");
+    assert_eq!(format!("{}", &hnf.u * &a), format!("{}", hnf.h));
+}
+
+#[test]
+fn hermite_normal_form_rectangular() {
+    let a: Matrix<i64> = Matrix::from(
+        [[2,3,6,2],
+         [5,6,1,6],
+         [8,3,1,1]]);
+    let hnf = hermite_normal_form(&a);
+    assert_eq!(format!("{}", &hnf.u * &a), format!("{}", hnf.h));
+    assert_eq!(integer_det(&hnf.u).abs(), 1);
+
+    // Echelon form with positive pivots and reduced entries above them
+    let mut last_pivot = None;
+    for row in 0..hnf.h.height() {
+        let Some(col) = (0..hnf.h.width()).find(|&c| hnf.h.get(row, c) != 0) else {
+            continue;
+        };
+        assert!(last_pivot.is_none_or(|p| p < col));
+        let pivot = hnf.h.get(row, col);
+        assert!(pivot > 0);
+        for above in 0..row {
+            assert!((0..pivot).contains(&hnf.h.get(above, col)));
+        }
+        last_pivot = Some(col);
+    }
+}
+
+#[test]
+fn hermite_normal_form_zero_column() {
+    let a: Matrix<i32> = Matrix::from([[0,3,1],[0,-6,4]]);
+    let hnf = hermite_normal_form(&a);
+    assert_eq!(format!("{}", hnf.h), "0,3,1\n0,0,6");
+    assert_eq!(format!("{}", &hnf.u * &a), format!("{}", hnf.h));
+}
+
+#[test]
+fn smith_normal_form_divisibility() {
+    let a: Matrix<i64> = Matrix::from(
+        [[2,4,4],
+         [-6,6,12],
+         [10,-4,-16]]);
+    let snf = smith_normal_form(&a);
+    assert_eq!(format!("{}", snf.d), "2,0,0\n0,6,0\n0,0,12");
+    assert_eq!(format!("{}", &(&snf.u * &a) * &snf.v), format!("{}", snf.d));
+    assert_eq!(integer_det(&snf.u).abs(), 1);
+    assert_eq!(integer_det(&snf.v).abs(), 1);
+}
+
+#[test]
+fn smith_normal_form_rank_deficient() {
+    let a: Matrix<i64> = Matrix::from([[1,2,3],[2,4,6]]);
+    let snf = smith_normal_form(&a);
+    assert_eq!(format!("{}", snf.d), "1,0,0\n0,0,0");
+    assert_eq!(format!("{}", &(&snf.u * &a) * &snf.v), format!("{}", snf.d));
+}
+
+#[test]
+fn integer_determinant() {
+    let a: Matrix<i64> = Matrix::from(
+        [[2,1,1],
+         [1,3,2],
+         [1,0,0]]);
+    assert_eq!(integer_det(&a), -1);
+
+    let b: Matrix<i64> = Matrix::from([[0,1,2],[3,4,5],[6,7,9]]);
+    assert_eq!(integer_det(&b), -3);
+    assert_eq!(integer_det(&Matrix::from([[1i64,2],[2,4]])), 0);
+}
+
+#[test]
+fn integer_determinant_bigint() {
+    let n: BigInt = "100000000000000000000".parse().unwrap();
+    let a = Matrix::from([[n.clone(), BigInt::one()], [BigInt::one(), n]]);
+    assert_eq!(format!("{}", integer_det(&a)), "9999999999999999999999999999999999999999");
+}
+
+#[test]
+#[should_panic(expected = "Cannot compute determinant of a non-square matrix! A=[1,2]")]
+fn integer_determinant_non_square() {
+    integer_det(&Matrix::from([[1i64, 2]]));
+}
+
+#[test]
+fn diophantine() {
+    let a: Matrix<i64> = Matrix::from([[2,4,6],[1,1,1]]);
+    let b: Matrix<i64> = Matrix::from([[8],[3]]);
+    let solution = solve_diophantine(&a, &b).unwrap();
+    assert_eq!(format!("{}", &a * &solution.particular), "8\n3");
+    assert_eq!(solution.kernel.width(), 1);
+    assert_eq!(format!("{}", &a * &solution.kernel), "0\n0");
+
+    let shifted = solution.particular.clone() + solution.kernel.clone() * 5i64;
+    assert_eq!(format!("{}", &a * &shifted), "8\n3");
+}
+
+#[test]
+fn diophantine_no_solution() {
+    let a: Matrix<i64> = Matrix::from([[2,4]]);
+    assert!(solve_diophantine(&a, &Matrix::from([[5i64]])).is_none());
+
+    let b: Matrix<i64> = Matrix::from([[1,1],[1,1]]);
+    assert!(solve_diophantine(&b, &Matrix::from([[1i64],[2]])).is_none());
+}
+
+#[test]
+fn diophantine_unique() {
+    let a: Matrix<i64> = Matrix::from([[2,1],[1,1]]);
+    let solution = solve_diophantine(&a, &Matrix::from([[5i64],[3]])).unwrap();
+    assert_eq!(format!("{}", solution.particular), "2\n1");
+    assert_eq!(solution.kernel.width(), 0);
+}
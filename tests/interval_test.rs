@@ -0,0 +1,124 @@
+use mm::{interval::*, lu_decomposition::*, matrix::*, rref::*};
+
+type I = Interval<f64>;
+
+#[test]
+fn outward_rounding() {
+    let sum = I::point(0.1) + I::point(0.2);
+    assert!(sum.lo() < sum.hi());
+    assert!(sum.contains(0.1 + 0.2));
+    assert!(sum.contains(0.3));
+
+    // Exact in floating point, but the bounds still move out
+    let exact = I::point(1.) + I::point(2.);
+    assert!(exact.lo() < 3. && exact.hi() > 3.);
+    assert!(exact.width() < 1e-14);
+}
+
+#[test]
+fn arithmetic() {
+    let a = I::new(-1., 2.);
+    let b = I::new(3., 4.);
+    let product = a * b;
+    assert!(product.contains(-4.) && product.contains(8.));
+    assert!(!product.contains(-4.1) && !product.contains(8.1));
+
+    let difference = a - b;
+    assert!(difference.contains(-5.) && difference.contains(-1.));
+    assert_eq!(-a, I::new(-2., 1.));
+
+    let quotient = b / I::new(2., 4.);
+    assert!(quotient.contains(0.75) && quotient.contains(2.));
+    assert_eq!(format!("{:.2}", quotient), "[0.75, 2.00]");
+    assert_eq!(a.mignitude(), 0.);
+    assert_eq!(b.mignitude(), 3.);
+    assert_eq!(a.hull(&b), I::new(-1., 4.));
+}
+
+#[test]
+#[should_panic(expected = "Division by an interval containing 0")]
+fn division_by_zero() {
+    let _ = I::point(1.) / I::new(-1., 1.);
+}
+
+#[test]
+#[should_panic(expected = "Lower bound of an interval can't be above the upper bound")]
+fn inverted_bounds() {
+    I::new(1., 0.);
+}
+
+#[test]
+fn matrix_multiply_encloses() {
+    let a = Matrix::from([[0.1, 0.2], [0.3, 0.4]]);
+    let b = Matrix::from([[0.5, 0.6], [0.7, 0.8]]);
+    let product = &to_interval_matrix(&a) * &to_interval_matrix(&b);
+    let point = &a * &b;
+    for row in 0..2 {
+        for col in 0..2 {
+            assert!(product.get(row, col).contains(point.get(row, col)));
+        }
+    }
+}
+
+#[test]
+fn solve_point_system() {
+    // Hilbert matrix, b picked so that x = [1, 1, 1]
+    let a = Matrix::from(
+        [[1., 1./2., 1./3.],
+         [1./2., 1./3., 1./4.],
+         [1./3., 1./4., 1./5.]]);
+    let b = Matrix::from([[11./6.], [13./12.], [47./60.]]);
+    let x = interval_lu_solve(&to_interval_matrix(&a), &to_interval_matrix(&b));
+    for row in 0..3 {
+        assert!(x.get(row, 0).contains(1.));
+        assert!(x.get(row, 0).width() < 1e-9);
+    }
+
+    let point = lu_solve(&lu_gauss(&a), &b);
+    for row in 0..3 {
+        assert!(x.get(row, 0).contains(point.get(row, 0)));
+    }
+}
+
+#[test]
+fn solve_uncertain_system() {
+    // Every entry is only known up to 1%
+    let a = Matrix::from(
+        [[I::around(4., 0.04), I::around(1., 0.01)],
+         [I::around(1., 0.01), I::around(3., 0.03)]]);
+    let b = Matrix::from([[I::around(1., 0.01)], [I::around(2., 0.02)]]);
+    let x = interval_lu_solve(&a, &b);
+
+    // Solutions for the corners of the data must all be inside
+    for a00 in [3.96, 4.04] {
+        for a11 in [2.97, 3.03] {
+            for b0 in [0.99, 1.01] {
+                let corner = Matrix::from([[a00, 1.], [1., a11]]);
+                let point = lu_solve(&lu_gauss(&corner), &Matrix::from([[b0], [2.]]));
+                assert!(x.get(0, 0).contains(point.get(0, 0)));
+                assert!(x.get(1, 0).contains(point.get(1, 0)));
+            }
+        }
+    }
+    assert!(x.get(0, 0).width() < 0.05);
+}
+
+#[test]
+#[should_panic(expected = "Division by an interval containing 0")]
+fn solve_singular_enclosure() {
+    let a = Matrix::from([[I::new(1., 2.), I::new(1., 2.)], [I::new(1., 2.), I::new(1., 2.)]]);
+    interval_lu_solve(&a, &Matrix::from([[I::point(1.)], [I::point(1.)]]));
+}
+
+#[test]
+fn unpivoted_column_keeps_enclosure() {
+
+    // [-1, 1] can't be a pivot, but it isn't 0 either - the column has to come back as it was
+    let a = Matrix::from([[I::new(-1., 1.), I::point(1.)], [I::new(-1., 1.), I::point(2.)]]);
+    let lu = lu_gauss(&a);
+    assert_eq!(lu.u.get(0, 0), I::new(-1., 1.));
+
+    let r = rref(&a);
+    assert_eq!(r.get(0, 0), I::new(-1., 1.));
+    assert_eq!(r.get(1, 0), I::new(-1., 1.));
+}
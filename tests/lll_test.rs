@@ -0,0 +1,103 @@
+use mm::{bigint::*, integer_matrix::*, lll::*, matrix::*, rational::*, scalar::*};
+use std::fmt::Display;
+
+fn check_reduced<T: Integer + Display>(result: &LLLResult<T>, a: &Matrix<T>, delta: Ratio<T>) {
+    assert_eq!(format!("{}", &result.transform * a), format!("{}", result.basis));
+    assert!(integer_det(&result.transform).abs().is_one());
+
+    let gs = gram_schmidt(&result.basis);
+    let half = Ratio::new(T::one(), T::one().add(&T::one()));
+    for i in 0..result.basis.height() {
+        for j in 0..i {
+            assert!(gs.mu.get(i, j).abs() <= half);
+        }
+        if i > 0 {
+            let mu = gs.mu.get(i, i - 1);
+            assert!(gs.norms_sqr[i] >= (delta.clone() - mu.clone() * mu) * gs.norms_sqr[i - 1].clone());
+        }
+    }
+}
+
+#[test]
+fn gram_schmidt_exact() {
+    let a: Matrix<i64> = Matrix::from([[3,1],[2,2]]);
+    let gs = gram_schmidt(&a);
+    assert_eq!(format!("{}", gs.orthogonal), "3,1\n-2/5,6/5");
+    assert_eq!(format!("{}", gs.mu), "1,0\n4/5,1");
+    assert_eq!(gs.norms_sqr, vec![Rational::from(10), Rational::new(8, 5)]);
+}
+
+#[test]
+#[should_panic(expected = "Rows of the basis must be linearly independent! Row 1 depends on the previous ones")]
+fn gram_schmidt_dependent() {
+    gram_schmidt(&Matrix::from([[1i64,2],[2,4]]));
+}
+
+#[test]
+fn lll_textbook() {
+    let a: Matrix<i64> = Matrix::from(
+        [[1,1,1],
+         [-1,0,2],
+         [3,5,6]]);
+    let delta = Rational::new(3, 4);
+    let result = lll(&a, delta);
+    check_reduced(&result, &a, delta);
+    assert_eq!(format!("{}", result.basis), "0,1,0\n1,0,1\n-2,0,1");
+}
+
+#[test]
+fn lll_full_delta() {
+    let a: Matrix<BigInt> = Matrix::from(
+        [[105,821,404,328],
+         [881,667,644,927],
+         [181,483,87,500],
+         [893,834,732,441]].map(|row| row.map(BigInt::from)));
+    let delta = Ratio::from(BigInt::from(1));
+    let result = lll(&a, delta.clone());
+    check_reduced(&result, &a, delta);
+    assert_eq!(integer_det(&result.basis).abs(), integer_det(&a).abs());
+}
+
+#[test]
+fn lll_bigint_knapsack() {
+    // Subset sum 3 + 11 + 47 = 61 hides as a short vector of the lattice
+    let weights = [3, 11, 19, 47, 83];
+    let target = 61;
+    let scale: BigInt = "1000000000000000000000".parse().unwrap();
+
+    let size = weights.len() + 1;
+    let mut a = Matrix::new_fill(size, size, BigInt::from(0));
+    for (i, &weight) in weights.iter().enumerate() {
+        a.set(i, i, BigInt::from(2));
+        a.set(i, size - 1, BigInt::from(weight) * scale.clone());
+    }
+    for i in 0..size - 1 {
+        a.set(size - 1, i, BigInt::from(1));
+    }
+    a.set(size - 1, size - 1, BigInt::from(target) * scale);
+
+    let result = lll(&a, Ratio::new(BigInt::from(99), BigInt::from(100)));
+    // The solution shows up as a row of +-1s, one of the signs marks the subset
+    let found = (0..size).any(|r| {
+        let row: Vec<i64> = (0..size).map(|c| result.basis.get(r, c).to_i64().unwrap()).collect();
+        row[size - 1] == 0 && row[..size - 1].iter().all(|x| x.abs() == 1) && [1, -1].iter().any(|sign| {
+            weights.iter().zip(&row).filter(|(_, x)| *x == sign).map(|(w, _)| w).sum::<i64>() == target
+        })
+    });
+    assert!(found);
+}
+
+#[test]
+#[should_panic(expected = "LLL delta must be in (1/4, 1]!")]
+fn lll_invalid_delta() {
+    lll(&Matrix::from([[1i64]]), Rational::new(1, 4));
+}
+
+#[test]
+fn rational_rounding() {
+    assert_eq!(Rational::new(7, 2).floor(), 3);
+    assert_eq!(Rational::new(-7, 2).floor(), -4);
+    assert_eq!(Rational::new(-7, 2).round(), -3);
+    assert_eq!(Rational::new(5, 3).round(), 2);
+    assert_eq!(Rational::new(-5, 3).round(), -2);
+}
@@ -1,4 +1,4 @@
-use mm::{lu_decomposition::*, matrix::*};
+use mm::{lu_decomposition::*, matrix::*, structured::*};
 mod common;
 
 #[test]
@@ -45,7 +45,11 @@ fn lu_solve_test() {
         [[1.,0.,2.],
          [0.,-1.,-1.],
          [0.,0.,-1.]]);
-    let lu = LUResult { l: l, u: u, p: Matrix::<f64>::identity(3)};
+    let lu = LUResult {
+        l: LowerTriangular::from_matrix(&l),
+        u: UpperTriangular::from_matrix(&u),
+        p: Permutation::identity(3)
+    };
     let b = Matrix::from([[-4.,-6.,-15.]]).transpose();
     let x = Matrix::from([[2.,1.,-3.]]).transpose();
 
@@ -75,7 +79,8 @@ fn permutation_matrix_identity() {
          [1.,0.,2.],
          [2.,0.5,3.]]);
     let res = lu_gauss(&a);
-    assert_eq!(format!("{:.2}", res.p), format!("{:.2}", Matrix::<f64>::identity(a.height()))); 
+    assert!(res.p.is_identity());
+    assert_eq!(format!("{}", res.p), format!("{}", Matrix::<u8>::identity(a.height())));
 }
 
 #[test]
@@ -102,4 +107,47 @@ fn tall_matrix() {
 
     let res = lu_gauss(&a);
     assert_eq!(format!("{:.2}\n", res.p * a), format!("{:.2}\n", res.l * res.u));
-}
\ No newline at end of file
+}
+
+#[test]
+fn inverse_f32() {
+    let a = Matrix::from(
+        [[1f32,0.,2.],
+         [2.,-1.,3.],
+         [4.,1.,8.]]);
+    let inv_a = Matrix::from(
+        [[-11f32,2.,2.],
+         [-4.,0.,1.],
+         [6.,-1.,-1.]]);
+
+    let res = inv(&a);
+    assert_eq!(format!("{:.2}", common::fix_zeroes(res)), format!("{:.2}", inv_a));
+}
+
+#[test]
+fn determinant() {
+    let a = Matrix::from(
+        [[1.,0.,2.],
+         [2.,-1.,3.],
+         [4.,1.,8.]]);
+    assert_eq!(format!("{:.6}", det(&a)), format!("{:.6}", 1.));
+
+    let singular = Matrix::from(
+        [[1.,2.,3.],
+         [2.,4.,6.],
+         [0.,1.,1.]]);
+    assert_eq!(det(&singular), 0.);
+}
+
+#[test]
+fn small_scale_matrix() {
+
+    // Well conditioned, only the scale is small - no column may be taken for 0
+    let a = Matrix::from(
+        [[2e-14,1e-14],
+         [1e-14,3e-14]]);
+    let b = Matrix::from([[1e-14,2e-14]]).transpose();
+    assert_eq!(format!("{:.6}", lu_solve(&lu_gauss(&a), &b)), "0.200000\n0.600000");
+    assert_eq!(format!("{:.2}", inv(&a) * 1e-13), "6.00,-2.00\n-2.00,4.00");
+    assert_eq!(format!("{:.6}", det(&a) * 1e28), "5.000000");
+}
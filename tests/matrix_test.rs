@@ -1,4 +1,4 @@
-use mm::matrix::*;
+use mm::{matrix::*, vector::*};
 
 #[test]
 fn display_precision() {
@@ -14,6 +14,7 @@ fn add_matrices1() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn add_matrices2() {
     let m = Matrix::from_str([["a","b","c"]]);
     let n = Matrix::from_str([["d","e","f"]]);
@@ -21,6 +22,7 @@ fn add_matrices2() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn sub_matrix_string() {
     let m = Matrix::from_str([["a+e","f+b","c"]]);
     let n = Matrix::from_str([["e","f","d"]]);
@@ -36,6 +38,7 @@ fn mul_matrices2x2_numbers() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn mul_matrices2x2_letters() {
     let m = Matrix::from_str([["a","b"],
                                               ["c","d"]]);
@@ -45,6 +48,7 @@ fn mul_matrices2x2_letters() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn mul_matrices3x1() {
     let m = Matrix::from_str([["a","b","c"],
                                               ["m","n","o"],
@@ -83,6 +87,7 @@ fn from() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn transpose_1() {
     let m = Matrix::from_str([["a","b","c"], ["d","e","f"]]);
     assert_eq!(format!("{}", m.transpose()), "a,d\nb,e\nc,f");
@@ -151,7 +156,7 @@ fn dot_product_different_sizes() {
 }
 
 #[test]
-#[should_panic="Dot operation only allowed on vectors! Matrix A has dim=2, Matrix B has dim=2"]
+#[should_panic="Dot operation only allowed on vectors! A=[2,3], B=[2,3]"]
 fn dot_product_matrix() {
 
     // transpose of a product equals a reverse product of transposes
@@ -160,6 +165,15 @@ fn dot_product_matrix() {
     assert_eq!(m.dot(&n), 59);
 }
 
+#[test]
+fn dot_product_empty() {
+
+    // Same as Vector::dot, no elements sum up to 0
+    let m: Matrix<i32> = Matrix::from_row_major(1, 0, []);
+    assert_eq!(m.dot(&m), 0);
+    assert_eq!(Vector::<i32>::new().dot(&Vector::new()), 0);
+}
+
 #[test]
 fn norm2_of_vector() {
     let m = Matrix::from([[-4,-3,-2,-1,0,1,2,3,4]]);
@@ -200,6 +214,7 @@ fn cut_2() {
 
 #[test]
 #[should_panic(expected = "Column index out of bounds: [3, 1] / [0, 2]")]
+#[allow(clippy::reversed_empty_ranges)]
 fn cut_bad_index() {
     let m = Matrix::from([[-4,-3,-2],[-1,0,1],[2,3,4]]);
     let _ = m.cut(..,3..2);
@@ -230,4 +245,175 @@ fn swap_rows_test() {
     let n: Matrix<i32> = Matrix::from([[-4,-3,-2,-1],[2,3,4,5],[-1,0,1,2],]);
     m.swap_rows(1,2);
     assert_eq!(format!("{}", m), format!("{}", n));
-}
\ No newline at end of file
+}
+#[test]
+fn index_elements() {
+    let mut m = Matrix::from([[1,2,3],[4,5,6]]);
+    assert_eq!(m[(1, 2)], 6);
+
+    m[(0, 1)] += 10;
+    m[1][0] = -4;
+    assert_eq!(format!("{}", m), "1,12,3\n-4,5,6");
+    assert_eq!(&m[0], &[1, 12, 3]);
+
+    m[1].swap(0, 2);
+    assert_eq!(format!("{}", m), "1,12,3\n6,5,-4");
+}
+
+#[test]
+#[allow(deprecated)]
+fn checked_access() {
+    let mut m = Matrix::from_str([["a","b"],["c","d"]]);
+    assert_eq!(m.get_ref(1, 0).map(String::as_str), Some("c"));
+    assert_eq!(m.get_ref(2, 0), None);
+    assert_eq!(m.get_ref(0, 2), None);
+
+    if let Some(value) = m.get_mut(0, 1) {
+        value.push('!');
+    }
+    assert!(m.get_mut(5, 5).is_none());
+    assert_eq!(format!("{}", m), "a,b!\nc,d");
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds! [1, 3] / [2, 3]")]
+fn index_out_of_bounds() {
+    let m = Matrix::from([[1,2,3],[4,5,6]]);
+    let _ = m[(1, 3)];
+}
+
+#[test]
+#[should_panic(expected = "Row index out of bounds! 2 / 2")]
+fn row_index_out_of_bounds() {
+    let mut m = Matrix::from([[1,2,3],[4,5,6]]);
+    m[2][0] = 1;
+}
+
+#[test]
+fn borrowed_operators() {
+    let a = Matrix::from([[1.,2.],[3.,4.]]);
+    let b = Matrix::from([[0.5,0.],[0.,2.]]);
+
+    assert_eq!(format!("{}", &a + &b), "1.5,2\n3,6");
+    assert_eq!(format!("{}", &a - b.clone()), "0.5,2\n3,2");
+    assert_eq!(format!("{}", a.clone() - &b), "0.5,2\n3,2");
+    assert_eq!(format!("{}", &a * &b), "0.5,4\n1.5,8");
+    assert_eq!(format!("{}", a.clone() * &b), "0.5,4\n1.5,8");
+    assert_eq!(format!("{}", &a * b.clone()), "0.5,4\n1.5,8");
+    assert_eq!(format!("{}", &a * 2.), "2,4\n6,8");
+    assert_eq!(format!("{}", &a / 2.), "0.5,1\n1.5,2");
+    assert_eq!(format!("{}", -&a), "-1,-2\n-3,-4");
+    assert_eq!(format!("{}", -a), "-1,-2\n-3,-4");
+}
+
+#[test]
+#[allow(deprecated)]
+fn borrowed_operators_keep_order() {
+
+    // String addition is concatenation, so the left operand has to stay on the left
+    let a = Matrix::from_str([["a","b"]]);
+    let b = Matrix::from_str([["c","d"]]);
+    assert_eq!(format!("{}", &a + b.clone()), "a+c,b+d");
+    assert_eq!(format!("{}", a.clone() + &b), "a+c,b+d");
+    assert_eq!(format!("{}", &b + &a), "c+a,d+b");
+}
+
+#[test]
+fn compound_assignment() {
+    let mut m = Matrix::from([[1,2],[3,4]]);
+    m += Matrix::from([[1,1],[1,1]]);
+    m -= &Matrix::from([[2,0],[0,2]]);
+    assert_eq!(format!("{}", m), "0,3\n4,3");
+
+    m *= 2;
+    assert_eq!(format!("{}", m), "0,6\n8,6");
+
+    m *= &Matrix::from([[1,0],[1,1]]);
+    assert_eq!(format!("{}", m), "6,6\n14,6");
+
+    m *= Matrix::identity(2);
+    assert_eq!(format!("{}", m), "6,6\n14,6");
+
+    let mut f = Matrix::from([[3.,6.]]);
+    f /= 3.;
+    assert_eq!(format!("{}", f), "1,2");
+}
+
+#[test]
+#[should_panic(expected = "Can't add matrices A+B: A.width (2) != B.width (3)")]
+fn add_assign_bad_sizes() {
+    let mut m = Matrix::new_fill(2, 2, 1);
+    m += &Matrix::new_fill(2, 3, 1);
+}
+
+#[test]
+#[should_panic(expected = "Can't sub matrices A-B: A.height (2) != B.height (3)")]
+fn sub_borrowed_bad_sizes() {
+    let m = Matrix::new_fill(2, 2, 1);
+    let _ = &m - Matrix::new_fill(3, 2, 1);
+}
+
+#[test]
+fn element_iterators() {
+    let mut m = Matrix::from([[1,2,3],[4,5,6]]);
+    assert_eq!(m.iter().sum::<i32>(), 21);
+    assert_eq!(m.iter().copied().collect::<Vec<_>>(), vec![1,2,3,4,5,6]);
+
+    for value in m.iter_mut() {
+        *value *= 10;
+    }
+    for value in &mut m {
+        *value += 1;
+    }
+    assert_eq!(format!("{}", m), "11,21,31\n41,51,61");
+    assert_eq!((&m).into_iter().max(), Some(&61));
+    assert_eq!(m.into_iter().rev().collect::<Vec<_>>(), vec![61,51,41,31,21,11]);
+}
+
+#[test]
+fn row_and_column_iterators() {
+    let m = Matrix::from([[1,2,3],[4,5,6]]);
+    let row_sums: Vec<i32> = m.row_iter().map(|row| row.iter().sum()).collect();
+    assert_eq!(row_sums, vec![6, 15]);
+
+    let col_sums: Vec<i32> = m.col_iter().map(|col| col.sum()).collect();
+    assert_eq!(col_sums, vec![5, 7, 9]);
+    assert_eq!(m.col_iter().len(), 3);
+    assert_eq!(m.col_iter().nth(1).unwrap().copied().collect::<Vec<_>>(), vec![2, 5]);
+}
+
+#[test]
+fn indexed_and_diagonal_iterators() {
+    let m = Matrix::from([[1,2,3],[4,5,6]]);
+    let below_diagonal: Vec<i32> = m.indexed_iter().filter(|((row, col), _)| row > col).map(|(_, &value)| value).collect();
+    assert_eq!(below_diagonal, vec![4]);
+    assert_eq!(m.indexed_iter().last(), Some(((1, 2), &6)));
+
+    assert_eq!(m.diag_iter().copied().collect::<Vec<_>>(), vec![1, 5]);
+    assert_eq!(Matrix::from([[1.,2.],[3.,4.]]).diag_iter().sum::<f64>(), 5.);
+}
+
+#[test]
+fn collect_into_matrix() {
+    let m: Matrix<i32> = (0..3).map(|row| vec![row, row * row]).collect();
+    assert_eq!(format!("{}", m), "0,0\n1,1\n2,4");
+
+    let copy: Matrix<i32> = m.row_iter().rev().collect();
+    assert_eq!(format!("{}", copy), "2,4\n1,1\n0,0");
+
+    let shaped = Matrix::from_row_major(2, 3, 1..=6);
+    assert_eq!(format!("{}", shaped), "1,2,3\n4,5,6");
+    assert_eq!(format!("{}", Matrix::from_row_major(3, 2, shaped.into_iter())), "1,2\n3,4\n5,6");
+}
+
+#[test]
+#[should_panic(expected = "Rows of a matrix must have the same length! Row 1 has 1 elements, expected 2")]
+fn collect_ragged_rows() {
+    let _: Matrix<i32> = vec![vec![1, 2], vec![3]].into_iter().collect();
+}
+
+#[test]
+#[should_panic(expected = "Expected 6 elements for a [2,3] matrix, got 7")]
+fn from_row_major_wrong_count() {
+    Matrix::from_row_major(2, 3, 0..7);
+}
@@ -0,0 +1,48 @@
+use std::{env, path::Path, process::Command};
+
+/*
+ * Builds the library without the std feature, which makes it #![no_std]. Any use of std
+ * outside the feature fails this. Float functions then come from the libm feature.
+ */
+#[test]
+fn builds_without_std() {
+    build_no_std(None);
+}
+
+/*
+ * Same build for a target that has no std at all, so a dependency pulling std in fails too.
+ * Opt-in since it needs an extra rustup target: MM_NO_STD_TARGET names it (CI sets
+ * thumbv7em-none-eabihf), and once it's set a missing target is a failure, not a skip.
+ */
+#[test]
+fn builds_for_bare_metal() {
+    let Ok(target) = env::var("MM_NO_STD_TARGET") else {
+        return;
+    };
+    assert!(target_installed(&target), "Target {target} from MM_NO_STD_TARGET isn't installed (rustup target add {target})");
+
+    build_no_std(Some(&target));
+}
+
+fn build_no_std(target: Option<&str>) {
+    let mut command = Command::new(env!("CARGO"));
+    command
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["build", "--lib", "--no-default-features", "--features", "libm"])
+        .env("CARGO_TARGET_DIR", concat!(env!("CARGO_MANIFEST_DIR"), "/target/no_std"));
+
+    if let Some(target) = target {
+        command.args(["--target", target]);
+    }
+
+    let output = command.output().expect("Failed to run cargo");
+    assert!(output.status.success(), "no_std build failed:\n{}", String::from_utf8_lossy(&output.stderr));
+}
+
+// The standard library of every installed target lives in the sysroot under lib/rustlib/<target>
+fn target_installed(target: &str) -> bool {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let output = Command::new(rustc).args(["--print", "sysroot"]).output().expect("Failed to run rustc");
+    let sysroot = String::from_utf8_lossy(&output.stdout);
+    Path::new(sysroot.trim()).join("lib/rustlib").join(target).join("lib").is_dir()
+}
@@ -0,0 +1,117 @@
+use mm::{lu_decomposition::*, matrix::*, norms::*};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9 * b.abs().max(1.)
+}
+
+#[test]
+fn matrix_norms() {
+    let a = Matrix::from([[1.,-2.],[-3.,4.]]);
+    assert_eq!(a.matrix_norm(Norm::One), 6.);
+    assert_eq!(a.matrix_norm(Norm::Inf), 7.);
+    assert_eq!(a.matrix_norm(Norm::Max), 4.);
+    assert!(close(a.matrix_norm(Norm::Frobenius), 30f64.sqrt()));
+
+    // sigma^2 are the eigenvalues of A^T A = [[10,-14],[-14,20]]
+    let root = 221f64.sqrt();
+    assert!(close(a.matrix_norm(Norm::Two), (15. + root).sqrt()));
+    assert!(close(a.matrix_norm(Norm::Nuclear), (15. + root).sqrt() + (15. - root).sqrt()));
+}
+
+#[test]
+fn singular_values() {
+    let a = Matrix::from([[3.,0.],[0.,-5.],[0.,0.]]);
+    assert_eq!(format!("{:?}", a.singular_values()), "[5.0, 3.0]");
+
+    // Wide matrices go through the transpose, a rank 1 matrix has one non-zero value
+    let b = Matrix::from([[1.,2.,3.],[2.,4.,6.]]);
+    let values = b.singular_values();
+    assert_eq!(values.len(), 2);
+    assert!(close(values[0], 70f64.sqrt()));
+    assert!(values[1].abs() < 1e-9);
+
+    // Frobenius norm is the length of the singular values
+    let c = Matrix::from([[2.,-1.,0.],[4.,3.,-2.],[1.,1.,5.]]);
+    let squares: f64 = c.singular_values().iter().map(|s| s * s).sum();
+    assert!(close(squares.sqrt(), c.matrix_norm(Norm::Frobenius)));
+}
+
+#[test]
+fn vector_p_norms() {
+    let v = Matrix::from([[3,-4]]);
+    assert_eq!(v.p_norm(1.), 7.);
+    assert_eq!(v.p_norm(2.), 5.);
+    assert_eq!(v.p_norm(f64::INFINITY), 4.);
+    assert!(close(v.p_norm(3.), 91f64.cbrt()));
+    assert_eq!(v.transpose().p_norm(2.), 5.);
+}
+
+#[test]
+#[should_panic(expected = "p-norm is only defined for vectors! A=[2,2]")]
+fn p_norm_of_matrix() {
+    Matrix::new_fill(2, 2, 1.).p_norm(2.);
+}
+
+#[test]
+#[should_panic(expected = "p-norm needs p >= 1, got 0.5")]
+fn p_norm_below_one() {
+    Matrix::new_fill(1, 2, 1.).p_norm(0.5);
+}
+
+#[test]
+fn condition_numbers() {
+    let a = Matrix::from([[4.,7.],[2.,6.]]);
+
+    // A^-1 = [[0.6,-0.7],[-0.2,0.4]]
+    assert!(close(cond(&a, Norm::One), 13. * 1.1));
+    assert!(close(cond(&a, Norm::Inf), 11. * 1.3));
+    let values = a.singular_values();
+    assert!(close(cond(&a, Norm::Two), values[0] / values[1]));
+
+    assert_eq!(cond(&Matrix::<f64>::identity(3), Norm::Two), 1.);
+    assert_eq!(cond(&Matrix::from([[1.,2.],[2.,4.]]), Norm::One), f64::INFINITY);
+    assert_eq!(cond(&Matrix::from([[1.,2.],[2.,4.]]), Norm::Two), f64::INFINITY);
+
+    // Small entries alone don't make a matrix singular
+    let small = Matrix::<f64>::identity(3) * 1e-14;
+    assert!(close(cond(&small, Norm::One), 1.));
+    assert!(close(cond(&small, Norm::Inf), 1.));
+    assert!(close(cond(&small, Norm::Two), 1.));
+    assert!(close(cond_estimate(&small, &lu_gauss(&small)), 1.));
+}
+
+#[test]
+#[should_panic(expected = "Cannot compute condition number of a non-square matrix! A=[2,3]")]
+fn cond_non_square() {
+    cond(&Matrix::new_fill(2, 3, 1.), Norm::One);
+}
+
+#[test]
+fn condition_estimate() {
+    let a = Matrix::from([[4.,7.],[2.,6.]]);
+    assert!(close(cond_estimate(&a, &lu_gauss(&a)), cond(&a, Norm::One)));
+
+    // Estimate is a lower bound and here exact
+    let b = Matrix::from([[2.,-1.,0.,0.],[-1.,2.,-1.,0.],[0.,-1.,2.,-1.],[0.,0.,-1.,2.]]);
+    let estimate = cond_estimate(&b, &lu_gauss(&b));
+    let exact = cond(&b, Norm::One);
+    assert!(estimate <= exact * (1. + 1e-12));
+    assert!(close(estimate, exact));
+
+    // Hilbert matrices are badly conditioned, the estimate has to notice
+    let hilbert = Matrix::from_row_major(6, 6, (0..36).map(|i| 1. / ((i / 6 + i % 6 + 1) as f64)));
+    let estimate = cond_estimate(&hilbert, &lu_gauss(&hilbert));
+    let exact = cond(&hilbert, Norm::One);
+    assert!(estimate > 1e7);
+    assert!(estimate <= exact * (1. + 1e-6) && estimate >= exact / 10.);
+
+    let singular = Matrix::from([[1.,2.],[2.,4.]]);
+    assert_eq!(cond_estimate(&singular, &lu_gauss(&singular)), f64::INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "LU doesn't belong to A! A=[3,3], LU size=2")]
+fn cond_estimate_mismatch() {
+    let a = Matrix::from([[4.,7.],[2.,6.]]);
+    cond_estimate(&Matrix::<f64>::identity(3), &lu_gauss(&a));
+}
@@ -95,6 +95,7 @@ fn qr_test_tall_matrix()
 }
 
 #[test]
+#[allow(clippy::vec_init_then_push)]
 fn compute_q()
 {
     let mut hs = vec![];
@@ -121,4 +122,44 @@ fn compute_q()
          [0.5,-0.5,-0.5,0.5]]);
 
     assert_eq!(format!("{:.2}", q_from_reflectors(&hs)), format!("{:.2}", q));
-}
\ No newline at end of file
+}
+
+#[test]
+fn least_squares()
+{
+    // Fit a line y = c0 + c1*t through (0, 1), (1, 3), (2, 4), (3, 8)
+    let a = Matrix::from(
+        [[1.,0.],
+         [1.,1.],
+         [1.,2.],
+         [1.,3.]]);
+    let b = Matrix::from([[1.,3.,4.,8.]]).transpose();
+    let x = qr_least_squares(&qr(&a), &b);
+    assert_eq!(format!("{:.2}", x), "0.70\n2.20");
+}
+
+#[test]
+fn least_squares_no_unknowns()
+{
+    let a: Matrix<f64> = Matrix::from_row_major(3, 0, []);
+    let b = Matrix::from([[1.,2.,3.]]).transpose();
+    assert!(qr_least_squares(&qr(&a), &b).is_empty());
+}
+
+#[test]
+fn qr_test_f32()
+{
+    let a = Matrix::from(
+        [[-1f32,-1.,1.],
+         [1.,3.,3.],
+         [-1.,-1.,5.],
+         [1.,3.,7.]]);
+
+    let r = Matrix::from(
+        [[2f32,4.,2.],
+         [0.,-2.,-8.],
+         [0.,0.,-4.]]);
+
+    let result = qr(&a);
+    assert_eq!(format!("{:.2}", common::fix_zeroes(result.r.cut(..3, ..))), format!("{:.2}", r));
+}
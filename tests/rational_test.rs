@@ -0,0 +1,81 @@
+use mm::{lu_decomposition::*, matrix::*, rational::*, rref::*};
+
+fn q<const M: usize, const N: usize>(arr: [[i64; M]; N]) -> Matrix<Rational> {
+    Matrix::from(arr.map(|row| row.map(Rational::from)))
+}
+
+#[test]
+fn arithmetic() {
+    let a = Rational::new(1, 3);
+    let b = Rational::new(-2, 6);
+    assert_eq!(b, Rational::new(-1, 3));
+    assert_eq!(a + b, Rational::from(0));
+    assert_eq!(a - b, Rational::new(2, 3));
+    assert_eq!(a * Rational::new(3, 4), Rational::new(1, 4));
+    assert_eq!(a / Rational::new(-1, 6), Rational::from(-2));
+    assert_eq!(Rational::new(3, -6), Rational::new(-1, 2));
+    assert!(Rational::new(1, 3) < Rational::new(1, 2));
+    assert_eq!(format!("{} {}", Rational::new(4, 6), Rational::new(4, 2)), "2/3 2");
+}
+
+#[test]
+#[should_panic(expected = "Denominator of a fraction can't be 0")]
+fn zero_denominator() {
+    let _ = Rational::new(1, 0);
+}
+
+#[test]
+fn exact_inverse() {
+    let a = q(
+        [[2,1,1],
+         [1,3,2],
+         [1,0,0]]);
+    let inv_a = inv(&a);
+    assert_eq!(format!("{}", inv_a), "0,0,1\n-2,1,3\n3,-1,-5");
+    assert!((&a * &inv_a).is_identity());
+
+    let b = q([[2,1],[1,3]]);
+    assert_eq!(format!("{}", inv(&b)), "3/5,-1/5\n-1/5,2/5");
+}
+
+#[test]
+fn exact_det() {
+    let a = q(
+        [[2,1,1],
+         [1,3,2],
+         [1,0,0]]);
+    assert_eq!(det(&a), Rational::from(-1));
+
+    let hilbert: Matrix<Rational> = Matrix::from(
+        [[1,2,3],[2,3,4],[3,4,5]].map(|row| row.map(|n| Rational::new(1, n))));
+    assert_eq!(det(&hilbert), Rational::new(1, 2160));
+
+    let singular = q([[1,2],[2,4]]);
+    assert_eq!(det(&singular), Rational::from(0));
+}
+
+#[test]
+fn exact_rref_and_rank() {
+    let a = q(
+        [[1,2,2,2],
+         [2,4,6,8],
+         [3,6,8,10]]);
+    assert_eq!(format!("{}", rref(&a)), "1,2,0,-2\n0,0,1,2\n0,0,0,0");
+    assert_eq!(rank(&a), 2);
+
+    // Thirds never become 0.3333333
+    let b = q([[3,1],[6,3]]);
+    assert_eq!(format!("{}", rref(&q([[3,1,1]]))), "1,1/3,1/3");
+    assert_eq!(rank(&b), 2);
+}
+
+#[test]
+fn exact_lu() {
+    let a = q(
+        [[1,3],
+         [2,1]]);
+    let lu = lu_gauss(&a);
+    assert_eq!(format!("{}", lu.l), "1,0\n1/2,1");
+    assert_eq!(format!("{}", lu.u), "2,1\n0,5/2");
+    assert_eq!(format!("{}", &lu.p * &a), format!("{}", &lu.l * &lu.u));
+}
@@ -102,3 +102,62 @@ fn get_rank() {
 //     let x = 5;
 // }
 
+#[test]
+fn wide_matrix_f32() {
+    let a = Matrix::from(
+        [[1f32,2.,2.,2.],
+         [2.,4.,6.,8.],
+         [3.,6.,8.,10.]]);
+
+    let r = Matrix::from(
+       [[1f32,2.,0.,-2.],
+        [0.,0.,1.,2.],
+        [0.,0.,0.,0.]]);
+
+    assert_eq!(rank(&a), 2);
+    assert_eq!(format!("{:.2}\n", r), format!("{:.2}\n", fix_zeroes(rref(&a))));
+}
+
+#[test]
+fn rank_small_scale() {
+
+    // Tolerances follow the size of the entries
+    let a = Matrix::from(
+        [[2e-14,1e-14],
+         [1e-14,3e-14]]);
+    assert_eq!(rank(&a), 2);
+    assert_eq!(rank(&(a * 1e20)), 2);
+
+    let singular = Matrix::from(
+        [[1e-14,2e-14],
+         [2e-14,4e-14]]);
+    assert_eq!(rank(&singular), 1);
+}
+
+#[test]
+fn null_space_f64() {
+    let a = Matrix::from(
+        [[1.,2.,3.],
+         [4.,5.,6.],
+         [7.,8.,9.]]);
+    let kernel = null_space(&a);
+    assert_eq!(format!("{:.2}", kernel), "1.00\n-2.00\n1.00");
+    assert_eq!(format!("{:.2}", fix_zeroes(&a * &kernel)), "0.00\n0.00\n0.00");
+
+    let full_rank = Matrix::from([[1.,0.],[0.,1.]]);
+    assert_eq!(null_space(&full_rank).width(), 0);
+}
+
+#[test]
+fn solve_underdetermined() {
+    let a = Matrix::from(
+        [[1.,2.,3.],
+         [4.,5.,6.]]);
+    let b = Matrix::from([[6.],[15.]]);
+    let x = solve(&a, &b).unwrap();
+    assert_eq!(format!("{:.2}", fix_zeroes(x.clone())), "0.00\n3.00\n0.00");
+    assert_eq!(format!("{:.2}", &a * &x), "6.00\n15.00");
+
+    let inconsistent = Matrix::from([[1.,1.],[2.,2.]]);
+    assert!(solve(&inconsistent, &Matrix::from([[1.],[3.]])).is_none());
+}
@@ -0,0 +1,79 @@
+use mm::{matrix::*, semiring::*};
+
+const INF: MinPlus<i64> = MinPlus::infinity();
+
+fn boolean<const M: usize, const N: usize>(arr: [[u8; M]; N]) -> Matrix<Boolean> {
+    Matrix::from(arr.map(|row| row.map(|x| Boolean(x == 1))))
+}
+
+#[test]
+fn boolean_reachability() {
+    // 0 -> 1 -> 2, 3 -> 0
+    let a = boolean(
+        [[0,1,0,0],
+         [0,0,1,0],
+         [0,0,0,0],
+         [1,0,0,0]]);
+    assert_eq!(format!("{}", a.pow(2)), "0,0,1,0\n0,0,0,0\n0,0,0,0\n0,1,0,0");
+    assert_eq!(format!("{}", a.pow(4)), "0,0,0,0\n0,0,0,0\n0,0,0,0\n0,0,0,0");
+    assert_eq!(format!("{}", a.closure()), "1,1,1,0\n0,1,1,0\n0,0,1,0\n1,1,1,1");
+}
+
+#[test]
+fn min_plus_shortest_paths() {
+    let w = |x: i64| MinPlus::new(x);
+    let a = Matrix::from(
+        [[w(0), w(4), INF,  w(10)],
+         [INF,  w(0), w(3), INF],
+         [INF,  INF,  w(0), w(2)],
+         [w(1), INF,  INF,  w(0)]]);
+    assert_eq!(format!("{}", a.closure()), "0,4,7,9\n6,0,3,5\n3,7,0,2\n1,5,8,0");
+
+    // Without the diagonal, A^k is the cheapest walk with exactly k edges
+    let b = Matrix::from(
+        [[INF,  w(1), w(5)],
+         [INF,  INF,  w(1)],
+         [w(2), INF,  INF]]);
+    assert_eq!(format!("{}", b.pow(2)), "7,inf,2\n3,inf,inf\ninf,3,7");
+    assert_eq!(format!("{}", &b * &b), format!("{}", b.pow(2)));
+}
+
+#[test]
+fn min_plus_f64() {
+    let a: Matrix<MinPlus<f64>> = Matrix::from(
+        [[0.0, 1.5],
+         [0.25, 0.0]].map(|row| row.map(MinPlus::new)));
+    assert_eq!(format!("{:.2}", a.pow(3)), "0.00,1.50\n0.25,0.00");
+    assert_eq!(format!("{}", Matrix::<MinPlus<f64>>::identity(2)), "0,inf\ninf,0");
+}
+
+#[test]
+fn max_plus_critical_path() {
+    // Task j can start once every predecessor i finished, a[j][i] = duration of i
+    let ninf = MaxPlus::infinity();
+    let d = |x: i64| MaxPlus::new(x);
+    let a = Matrix::from(
+        [[ninf, ninf, ninf, ninf],
+         [d(3), ninf, ninf, ninf],
+         [d(3), ninf, ninf, ninf],
+         [ninf, d(2), d(5), ninf]]);
+
+    // Earliest start of every task when task 0 starts at 0
+    let start = Matrix::from([[d(0)], [ninf], [ninf], [ninf]]);
+    let earliest = &a.closure() * &start;
+    assert_eq!(format!("{}", earliest), "0\n3\n3\n8");
+    assert_eq!(format!("{}", a.pow(2)), "-inf,-inf,-inf,-inf\n-inf,-inf,-inf,-inf\n-inf,-inf,-inf,-inf\n8,-inf,-inf,-inf");
+}
+
+#[test]
+fn pow_ordinary() {
+    let fibonacci: Matrix<i64> = Matrix::from([[1,1],[1,0]]);
+    assert_eq!(format!("{}", fibonacci.pow(10)), "89,55\n55,34");
+    assert!(fibonacci.pow(0).is_identity());
+}
+
+#[test]
+#[should_panic(expected = "Cannot raise a non-square matrix to a power! A=[1,2]")]
+fn pow_non_square() {
+    Matrix::from([[1i64, 2]]).pow(2);
+}
@@ -0,0 +1,131 @@
+use mm::{block, matrix::*, shape::*, statistics::Axis};
+
+#[test]
+fn reshape() {
+    let a = Matrix::from([[1,2,3],[4,5,6]]);
+    assert_eq!(format!("{}", a.reshape(3, 2)), "1,2\n3,4\n5,6");
+    assert_eq!(format!("{}", a.reshape(1, 6)), "1,2,3,4,5,6");
+    assert_eq!(format!("{}", a.reshape(6, 1).reshape(2, 3)), format!("{a}"));
+}
+
+#[test]
+#[should_panic(expected = "Can't reshape a [2,3] matrix into [4,2]")]
+fn reshape_wrong_size() {
+    Matrix::from([[1,2,3],[4,5,6]]).reshape(4, 2);
+}
+
+#[test]
+fn stacking() {
+    let a = Matrix::from([[1,2],[3,4]]);
+    let b = Matrix::from([[5],[6]]);
+    let c = Matrix::from([[7,8]]);
+    assert_eq!(format!("{}", hstack(&[&a, &b])), "1,2,5\n3,4,6");
+    assert_eq!(format!("{}", vstack(&[&a, &c, &c])), "1,2\n3,4\n7,8\n7,8");
+    assert_eq!(format!("{}", concat(&[&a, &b], Axis::Row)), "1,2,5\n3,4,6");
+    assert_eq!(format!("{}", concat(&[&a, &c], Axis::Col)), "1,2\n3,4\n7,8");
+    assert!(hstack::<i32>(&[]).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Can't stack matrices horizontally: matrix 1 has height 1, expected 2")]
+fn hstack_different_heights() {
+    hstack(&[&Matrix::from([[1,2],[3,4]]), &Matrix::from([[7,8]])]);
+}
+
+#[test]
+#[should_panic(expected = "Can't stack matrices vertically: matrix 2 has width 1, expected 2")]
+fn vstack_different_widths() {
+    let a = Matrix::from([[1,2],[3,4]]);
+    vstack(&[&a, &a, &Matrix::from([[5],[6]])]);
+}
+
+#[test]
+fn block_matrix() {
+    let a = Matrix::from([[1,2],[3,4]]);
+    let b = Matrix::from([[5],[6]]);
+    let c = Matrix::from([[7,8]]);
+    let m = block![
+        [a, b],
+        [c, Matrix::from([[9]])],
+    ];
+    assert_eq!(format!("{}", m), "1,2,5\n3,4,6\n7,8,9");
+
+    // [[A, 0], [0, I]]
+    let z = Matrix::new_fill(2, 2, 0);
+    let m = block![[a, z], [z, Matrix::identity(2)]];
+    assert_eq!(format!("{}", m), "1,2,0,0\n3,4,0,0\n0,0,1,0\n0,0,0,1");
+}
+
+#[test]
+fn column_and_row_ops() {
+    let mut a = Matrix::from([[1,2,3],[4,5,6]]);
+    a.swap_cols(0, 2);
+    assert_eq!(format!("{}", a), "3,2,1\n6,5,4");
+
+    a.insert_row(1, vec![7,8,9]);
+    assert_eq!(format!("{}", a), "3,2,1\n7,8,9\n6,5,4");
+    a.insert_col(3, vec![0,0,0]);
+    assert_eq!(format!("{}", a), "3,2,1,0\n7,8,9,0\n6,5,4,0");
+
+    assert_eq!(a.remove_row(0), vec![3,2,1,0]);
+    assert_eq!(a.remove_col(1), vec![8,5]);
+    assert_eq!(format!("{}", a), "7,9,0\n6,4,0");
+
+    // Empty matrices take their size from the first row or column
+    let mut b: Matrix<i32> = Matrix::new();
+    b.insert_col(0, vec![1,2]);
+    b.insert_col(1, vec![3,4]);
+    assert_eq!(format!("{}", b), "1,3\n2,4");
+    let mut c: Matrix<i32> = Matrix::new();
+    c.insert_row(0, vec![1,2,3]);
+    assert_eq!(format!("{}", c), "1,2,3");
+}
+
+#[test]
+#[should_panic(expected = "Row must have 3 elements, got 2")]
+fn insert_row_wrong_length() {
+    Matrix::from([[1,2,3]]).insert_row(0, vec![1,2]);
+}
+
+#[test]
+#[should_panic(expected = "Column index out of bounds! 3 / 3")]
+fn remove_col_out_of_bounds() {
+    Matrix::from([[1,2,3]]).remove_col(3);
+}
+
+#[test]
+#[should_panic(expected = "Column index out of bounds! 2 / 2")]
+fn swap_cols_out_of_bounds() {
+    Matrix::from([[1,2]]).swap_cols(0, 2);
+}
+
+#[test]
+fn select_and_flip() {
+    let a = Matrix::from([[1,2,3],[4,5,6],[7,8,9]]);
+    assert_eq!(format!("{}", a.select_rows(&[2,0,2])), "7,8,9\n1,2,3\n7,8,9");
+    assert_eq!(format!("{}", a.select_cols(&[1])), "2\n5\n8");
+    assert_eq!(format!("{}", a.flip(Axis::Row)), "3,2,1\n6,5,4\n9,8,7");
+    assert_eq!(format!("{}", a.flip(Axis::Col)), "7,8,9\n4,5,6\n1,2,3");
+}
+
+#[test]
+#[should_panic(expected = "Row index out of bounds! 3 / 3")]
+fn select_rows_out_of_bounds() {
+    Matrix::from([[1],[2],[3]]).select_rows(&[0, 3]);
+}
+
+#[test]
+fn tile_and_repeat() {
+    let a = Matrix::from([[1,2]]);
+    assert_eq!(format!("{}", a.tile(2, 3)), "1,2,1,2,1,2\n1,2,1,2,1,2");
+    assert_eq!(format!("{}", a.repeat(2, 3)), "1,1,1,2,2,2\n1,1,1,2,2,2");
+    assert!(a.tile(0, 3).is_empty());
+
+    // Zero copies across keeps the rows, zero copies down leaves none, same as repeat
+    let b = Matrix::from([[1,2,3]]);
+    for (down, across) in [(2, 0), (0, 2), (0, 0)] {
+        let (tiled, repeated) = (b.tile(down, across), b.repeat(down, across));
+        assert_eq!((tiled.height(), tiled.width()), (repeated.height(), repeated.width()));
+    }
+    assert_eq!((b.tile(2, 0).height(), b.tile(2, 0).width()), (2, 0));
+}
@@ -0,0 +1,115 @@
+use mm::{lu_decomposition::*, matrix::*, rational::*, static_matrix::*};
+mod common;
+use common::fix_zeroes;
+
+#[test]
+fn construction() {
+    let a = SMatrix::from([[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.height(), 2);
+    assert_eq!(a.width(), 3);
+    assert_eq!(format!("{}", a.transpose()), "1,4\n2,5\n3,6");
+    assert_eq!(format!("{}", SMatrix3::<i32>::identity()), "1,0,0\n0,1,0\n0,0,1");
+    assert_eq!(SMatrix::<usize, 2, 2>::from_fn(|row, col| row * 2 + col), SMatrix::new([[0, 1], [2, 3]]));
+
+    let mut b = SMatrix::<f64, 2, 2>::new_fill(0.);
+    b.set(1, 0, 2.5);
+    assert_eq!(b.get(1, 0), 2.5);
+    assert_eq!(b.into_array(), [[0., 0.], [2.5, 0.]]);
+
+    b[(0, 1)] = 1.;
+    b[1][1] = 3.;
+    assert_eq!(b[(1, 0)], 2.5);
+    assert_eq!(b[1], [2.5, 3.]);
+    assert_eq!(format!("{}", b), "0,1\n2.5,3");
+}
+
+#[test]
+fn arithmetic() {
+    let a = SMatrix::from([[1, 2, 3], [4, 5, 6]]);
+    let b = SMatrix::from([[1, 0], [0, 1], [1, 1]]);
+
+    // 2x3 * 3x2 = 2x2, a 2x3 * 2x3 product wouldn't compile
+    let c: SMatrix<i32, 2, 2> = a * b;
+    assert_eq!(format!("{}", c), "4,5\n10,11");
+    assert_eq!(format!("{}", b * a), "1,2,3\n4,5,6\n5,7,9");
+    assert_eq!(format!("{}", a + a - a * 2), "0,0,0\n0,0,0");
+
+    // Inner size 0 sums nothing
+    let empty: SMatrix<i32, 2, 3> = SMatrix::<i32, 2, 0>::from([[], []]) * SMatrix::<i32, 0, 3>::from([]);
+    assert_eq!(format!("{}", empty), "0,0,0\n0,0,0");
+}
+
+#[test]
+fn matrix_conversion() {
+    let a = SMatrix::from([[1., 2.], [3., 4.]]);
+    let m: Matrix<f64> = a.into();
+    assert_eq!(format!("{}", m), format!("{}", a));
+    assert_eq!(SMatrix2::from_matrix(&m), a);
+    assert_eq!(format!("{}", a.to_matrix() * Matrix::from([[1.], [1.]])), "3\n7");
+}
+
+#[test]
+#[should_panic(expected = "Matrix doesn't fit the static size! A=[2,2], expected=[3,3]")]
+fn matrix_conversion_wrong_size() {
+    SMatrix3::<f64>::from_matrix(&Matrix::from([[1., 2.], [3., 4.]]));
+}
+
+#[test]
+fn decompositions() {
+    let a = SMatrix::from(
+        [[2., 1., 1.],
+         [4., -6., 0.],
+         [-2., 7., 2.]]);
+
+    assert_eq!(format!("{:.2}", a.det()), "-16.00");
+    assert_eq!(format!("{:.2}", fix_zeroes((a * a.inv()).to_matrix())), "1.00,0.00,0.00\n0.00,1.00,0.00\n0.00,0.00,1.00");
+
+    let b = SMatrix::from([[5.], [-2.], [9.]]);
+    let x: SMatrix<f64, 3, 1> = a.solve(&b);
+    assert_eq!(format!("{:.2}", x), "1.00\n1.00\n2.00");
+
+    let lu = a.lu();
+    assert_eq!(format!("{:.2}", lu.u), "4.00,-6.00,0.00\n0.00,4.00,1.00\n0.00,0.00,1.00");
+    assert_eq!(format!("{:.2}", lu.l), "1.00,0.00,0.00\n0.50,1.00,0.00\n-0.50,1.00,1.00");
+    assert_eq!(lu.p, [1, 0, 2]);
+
+    let qr = a.qr();
+    assert_eq!(format!("{:.2}", qr.q * qr.r), format!("{:.2}", a));
+    assert_eq!(format!("{:.2}", fix_zeroes((qr.q.transpose() * qr.q).to_matrix())), "1.00,0.00,0.00\n0.00,1.00,0.00\n0.00,0.00,1.00");
+    assert_eq!(format!("{:.2}", fix_zeroes(qr.r.to_matrix())), "-4.90,7.35,0.41\n0.00,5.66,2.12\n0.00,0.00,-0.58");
+
+    // Singular matrices keep a zero pivot
+    let singular = SMatrix::from([[1., 2.], [2., 4.]]);
+    assert_eq!(singular.det(), 0.);
+    assert_eq!(format!("{}", singular.lu().u), "2,4\n0,0");
+}
+
+#[test]
+fn lu_matches_lu_gauss() {
+    let regular = SMatrix::from(
+        [[1., 2., 0., 3.],
+         [4., -1., 2., 0.],
+         [0., 5., 1., -2.],
+         [2., 2., 2., 2.]]);
+    let singular = SMatrix::from(
+        [[1., 2., 3., 4.],
+         [2., 4., 6., 8.],
+         [0., 1., 0., 1.],
+         [1., 3., 3., 5.]]);
+
+    for a in [regular, singular] {
+        let lu = a.lu();
+        let reference = lu_gauss(&a.to_matrix());
+        assert_eq!(format!("{}", lu.l), format!("{}", reference.l.to_matrix()));
+        assert_eq!(format!("{}", lu.u), format!("{}", reference.u.to_matrix()));
+        let p = SMatrix::<f64, 4, 4>::from_fn(|row, col| if lu.p[row] == col { 1. } else { 0. });
+        assert_eq!(format!("{}", p), format!("{}", reference.p.to_matrix::<f64>()));
+    }
+}
+
+#[test]
+fn exact_elements() {
+    let a = SMatrix::from([[Ratio::new(1, 2), Ratio::from(1)], [Ratio::from(3), Ratio::from(4)]]);
+    assert_eq!(format!("{}", a.det()), "-1");
+    assert_eq!(format!("{}", a.inv()), "-4,1\n3,-1/2");
+}
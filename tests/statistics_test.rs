@@ -0,0 +1,88 @@
+use mm::{matrix::*, rational::*, statistics::*};
+
+#[test]
+fn sums_and_products() {
+    let a = Matrix::from([[1,2,3],[4,5,6]]);
+    assert_eq!(a.sum(), 21);
+    assert_eq!(a.product(), 720);
+    assert_eq!(format!("{}", a.sum_axis(Axis::Row)), "6\n15");
+    assert_eq!(format!("{}", a.sum_axis(Axis::Col)), "5,7,9");
+    assert_eq!(format!("{}", a.product_axis(Axis::Col)), "4,10,18");
+    assert_eq!(Matrix::<i32>::new().sum(), 0);
+}
+
+#[test]
+fn trace_and_cumsum() {
+    let a = Matrix::from([[1,2,3],[4,5,6],[7,8,9]]);
+    assert_eq!(a.trace(), 15);
+    assert_eq!(format!("{}", a.cumsum(Axis::Row)), "1,3,6\n4,9,15\n7,15,24");
+    assert_eq!(format!("{}", a.cumsum(Axis::Col)), "1,2,3\n5,7,9\n12,15,18");
+}
+
+#[test]
+#[should_panic(expected = "Cannot compute trace of a non-square matrix! A=[2,3]")]
+fn trace_non_square() {
+    Matrix::new_fill(2, 3, 1).trace();
+}
+
+#[test]
+fn extremes() {
+    let a = Matrix::from([[3.,-1.,f64::NAN],[-1.,7.,2.]]);
+    assert_eq!(a.min(), Some(-1.));
+    assert_eq!(a.max(), Some(7.));
+    assert_eq!(a.argmin(), Some((0, 1)));
+    assert_eq!(a.argmax(), Some((1, 1)));
+    assert_eq!(format!("{}", a.min_axis(Axis::Row)), "-1\n-1");
+    assert_eq!(format!("{}", a.max_axis(Axis::Col)), "3,7,2");
+    assert_eq!(a.argmin_axis(Axis::Row), vec![1, 0]);
+    assert_eq!(a.argmax_axis(Axis::Col), vec![0, 1, 1]);
+    assert_eq!(Matrix::<i32>::new().max(), None);
+}
+
+#[test]
+fn mean_and_variance() {
+    let a = Matrix::from([[2.,4.],[4.,8.],[6.,12.]]);
+    assert_eq!(a.mean(), 6.);
+    assert_eq!(format!("{}", a.mean_axis(Axis::Col)), "4,8");
+    assert_eq!(format!("{}", a.mean_axis(Axis::Row)), "3\n6\n9");
+    assert_eq!(format!("{}", a.variance_axis(Axis::Col)), "4,16");
+    assert_eq!(format!("{}", a.std_axis(Axis::Col)), "2,4");
+    assert_eq!(format!("{:.4}", a.variance()), "12.8000");
+    assert_eq!(format!("{:.4}", a.std()), "3.5777");
+}
+
+#[test]
+fn exact_mean_and_variance() {
+    let a = Matrix::from([[Ratio::from(1), Ratio::from(2), Ratio::from(4)]]);
+    assert_eq!(format!("{}", a.mean()), "7/3");
+    assert_eq!(format!("{}", a.variance()), "7/3");
+}
+
+#[test]
+fn covariance_and_correlation() {
+
+    // Second column is twice the first, third goes the other way
+    let a = Matrix::from(
+        [[1., 2., 3.],
+         [2., 4., 1.],
+         [3., 6., 2.]]);
+
+    assert_eq!(format!("{:.2}", a.covariance()), "1.00,2.00,-0.50\n2.00,4.00,-1.00\n-0.50,-1.00,1.00");
+    assert_eq!(format!("{:.2}", a.correlation()), "1.00,1.00,-0.50\n1.00,1.00,-0.50\n-0.50,-0.50,1.00");
+
+    // The diagonal of the covariance is the variance of each column
+    assert_eq!(format!("{:.2}", Matrix::from_row_major(1, 3, a.covariance().diag_iter().copied())), format!("{:.2}", a.variance_axis(Axis::Col)));
+}
+
+#[test]
+#[should_panic(expected = "Sample variance needs at least 2 values, got 1")]
+fn covariance_single_observation() {
+    Matrix::from([[1., 2.]]).covariance();
+}
+
+#[test]
+#[should_panic(expected = "Cannot reduce empty rows or columns! A=[2,0]")]
+fn min_of_empty_rows() {
+    let a: Matrix<i32> = vec![vec![], vec![]].into_iter().collect();
+    a.min_axis(Axis::Row);
+}
@@ -0,0 +1,125 @@
+use mm::{matrix::*, structured::*, lu_decomposition::*};
+mod common;
+
+#[test]
+fn diagonal_mul() {
+    let d = Diagonal::new(vec![2, 3]);
+    let m = Matrix::from([[1,2],[3,4]]);
+    assert_eq!(format!("{}", &d * &m), "2,4\n9,12");
+    assert_eq!(format!("{}", m * d), "2,6\n6,12");
+}
+
+#[test]
+fn diagonal_solve_inverse_det() {
+    let d = Diagonal::new(vec![2., 4., 0.5]);
+    let b = Matrix::from([[1.,2.,3.]]).transpose();
+    assert_eq!(format!("{:.2}", d.solve(&b)), "0.50\n0.50\n6.00");
+    assert_eq!(format!("{:.2}", d.inverse()), "0.50,0.00,0.00\n0.00,0.25,0.00\n0.00,0.00,2.00");
+    assert_eq!(d.determinant(), 4.);
+}
+
+#[test]
+fn identity_is_lightweight() {
+    let d: Diagonal<i32> = Diagonal::identity(3);
+    assert_eq!(d.diagonal(), &[1, 1, 1]);
+    assert!(Matrix::from(d).is_identity());
+    assert!(Permutation::identity(3).is_identity());
+}
+
+#[test]
+fn triangular_from_matrix() {
+    let a = Matrix::from(
+        [[1,2,3],
+         [4,5,6],
+         [7,8,9]]);
+    assert_eq!(format!("{}", LowerTriangular::from_matrix(&a)), "1,0,0\n4,5,0\n7,8,9");
+    assert_eq!(format!("{}", UpperTriangular::from_matrix(&a)), "1,2,3\n0,5,6\n0,0,9");
+    assert_eq!(format!("{}", LowerTriangular::from_matrix(&a).transpose()), "1,4,7\n0,5,8\n0,0,9");
+}
+
+#[test]
+fn upper_trapezoid() {
+    let a = Matrix::from(
+        [[1,2],
+         [3,4],
+         [5,6]]);
+    let u = UpperTriangular::from_matrix(&a);
+    assert_eq!(u.height(), 3);
+    assert_eq!(u.width(), 2);
+    assert_eq!(format!("{}", u), "1,2\n0,4\n0,0");
+}
+
+#[test]
+fn triangular_products_keep_structure() {
+    let l = LowerTriangular::from_matrix(&Matrix::from([[1,0],[2,3]]));
+    let u = UpperTriangular::from_matrix(&Matrix::from([[4,5],[0,6]]));
+    assert_eq!(format!("{}", &l * &l), "1,0\n8,9");
+    assert_eq!(format!("{}", &u * &u), "16,50\n0,36");
+    assert_eq!(format!("{}", l * u), "4,5\n8,28");
+}
+
+#[test]
+fn triangular_solve_inverse_det() {
+    let l = LowerTriangular::from_matrix(&Matrix::from(
+        [[2.,0.,0.],
+         [1.,1.,0.],
+         [4.,-1.,2.]]));
+    let b = Matrix::from([[2.,3.,6.]]).transpose();
+    assert_eq!(format!("{:.2}", l.solve(&b)), "1.00\n2.00\n2.00");
+    assert_eq!(format!("{:.2}", &l * &l.inverse()), format!("{:.2}", Matrix::<f64>::identity(3)));
+    assert_eq!(l.determinant(), 4.);
+
+    let u = l.transpose();
+    let b = Matrix::from([[15.,1.,4.]]).transpose();
+    assert_eq!(format!("{:.2}", u.solve(&b)), "2.00\n3.00\n2.00");
+    assert_eq!(format!("{:.2}", common::fix_zeroes(&u * &u.inverse().to_matrix())), format!("{:.2}", Matrix::<f64>::identity(3)));
+    assert_eq!(u.determinant(), 4.);
+}
+
+#[test]
+fn symmetric() {
+    let a = Matrix::from(
+        [[4.,1.,2.],
+         [1.,3.,0.],
+         [2.,0.,5.]]);
+    let s = Symmetric::from_matrix(&a);
+    assert_eq!(format!("{}", s), format!("{}", a));
+
+    let b = Matrix::from([[1.,2.,3.]]).transpose();
+    assert_eq!(format!("{:.2}", &s * &s.solve(&b)), format!("{:.2}", b));
+    assert_eq!(format!("{:.2}", common::fix_zeroes(&s * &s.inverse().to_matrix())), format!("{:.2}", Matrix::<f64>::identity(3)));
+    assert_eq!(format!("{:.6}", s.determinant()), format!("{:.6}", 43.));
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not symmetric! A[1, 0] != A[0, 1]")]
+fn symmetric_not_symmetric() {
+    let _ = Symmetric::from_matrix(&Matrix::from([[1,2],[3,4]]));
+}
+
+#[test]
+fn permutation() {
+    let p = Permutation::from_vec(vec![2, 0, 1]);
+    let m = Matrix::from([[1,2,3],[4,5,6],[7,8,9]]);
+    assert_eq!(format!("{}", p), "0,0,1\n1,0,0\n0,1,0");
+    assert_eq!(format!("{}", &p * &m), "7,8,9\n1,2,3\n4,5,6");
+    assert_eq!(format!("{}", &m * &p), format!("{}", &m * &p.to_matrix()));
+    assert_eq!(format!("{}", p.solve(&(&p * &m))), format!("{}", m));
+    assert!((&p * &p.inverse()).is_identity());
+    assert_eq!(p.determinant(), 1);
+
+    let mut q = Permutation::identity(3);
+    q.swap(0, 2);
+    assert_eq!(q.determinant(), -1);
+    assert_eq!(format!("{}", &p * &q), format!("{}", &p.to_matrix::<i32>() * &q.to_matrix()));
+}
+
+#[test]
+fn lu_result_is_structured() {
+    let a = Matrix::from(
+        [[1.,2.],
+         [3.,4.]]);
+    let lu = lu_gauss(&a);
+    assert_eq!(format!("{:.2}", &lu.p * &a), format!("{:.2}", &lu.l * &lu.u));
+    assert_eq!(format!("{:.6}", lu.p.determinant() as f64 * lu.u.determinant()), format!("{:.6}", -2.));
+}
@@ -0,0 +1,115 @@
+use mm::{dual::*, matrix::*, tape::*};
+
+#[test]
+fn least_squares_gradient() {
+    // |Wx - y|^2, dW = 2 (Wx - y) x^T
+    let tape = Tape::new();
+    let w = tape.var(Matrix::from([[1.,2.],[3.,4.]]));
+    let x = tape.var(Matrix::from([[1.],[-1.]]));
+    let y = tape.var(Matrix::from([[0.],[2.]]));
+
+    let residual = w * x - y;
+    let norm = residual.norm();
+    let loss = norm * norm;
+    assert_eq!(format!("{:.2}", loss.value()), "10.00");
+
+    let grads = loss.backward();
+    assert_eq!(format!("{:.2}", grads.wrt(&w)), "-2.00,2.00\n-6.00,6.00");
+    assert_eq!(format!("{:.2}", grads.wrt(&x)), "-20.00\n-28.00");
+    assert_eq!(format!("{:.2}", grads.wrt(&y)), "2.00\n6.00");
+}
+
+#[test]
+fn shared_subexpressions_accumulate() {
+    // x.x + 3 x.x = 4 |x|^2, gradient 8x
+    let tape = Tape::new();
+    let x = tape.var(Matrix::from([[1., 2., 3.]]));
+    let d = x.dot(&x);
+    let loss = d + d * 3.;
+    assert_eq!(format!("{:.2}", loss.backward().wrt(&x)), "8.00,16.00,24.00");
+    assert_eq!(tape.len(), 4);
+}
+
+#[test]
+fn dot_of_row_and_column() {
+    // Each gradient keeps the shape of its own operand
+    let tape = Tape::new();
+    let x = tape.var(Matrix::from([[1., 2.]]));
+    let y = tape.var(Matrix::from([[3.], [4.]]));
+    let grads = x.dot(&y).backward();
+    assert_eq!(format!("{:.2}", grads.wrt(&x)), "3.00,4.00");
+    assert_eq!(format!("{:.2}", grads.wrt(&y)), "1.00\n2.00");
+}
+
+#[test]
+fn transpose_and_scalar() {
+    // sum of entries of 2 A^T, through a product with ones
+    let tape = Tape::new();
+    let a = tape.var(Matrix::from([[1.,2.,3.],[4.,5.,6.]]));
+    let left = tape.var(Matrix::from([[1.,1.,1.]]));
+    let right = tape.var(Matrix::from([[1.],[1.]]));
+    let loss = left * (2. * a.transpose()) * right;
+    assert_eq!(format!("{:.2}", loss.value()), "42.00");
+    assert_eq!(format!("{:.2}", loss.backward().wrt(&a)), "2.00,2.00,2.00\n2.00,2.00,2.00");
+}
+
+#[test]
+fn activations_match_forward_mode() {
+    let w_value = Matrix::from([[0.5,-1.],[2.,0.25]]);
+    let x_value = Matrix::from([[1.],[0.5]]);
+
+    let tape = Tape::new();
+    let w = tape.var(w_value.clone());
+    let x = tape.var(x_value.clone());
+    let hidden = (w * x).tanh();
+    let out = (w * hidden).sigmoid();
+    let loss = out.transpose().dot(&out.transpose()) + (w * x).relu().norm();
+    let reverse = loss.backward().wrt(&w);
+
+    // Same function written with duals, differentiated one entry at a time
+    let forward = gradient(|w| {
+        let x = Matrix::from([[Dual::constant(x_value.get(0, 0))], [Dual::constant(x_value.get(1, 0))]]);
+        let map = |m: Matrix<Dual<f64>>, f: fn(&Dual<f64>) -> Dual<f64>| {
+            let mut ret = m.clone();
+            for row in 0..m.height() {
+                ret.set(row, 0, f(&m.get(row, 0)));
+            }
+            ret
+        };
+        let tanh = |d: &Dual<f64>| d.chain(d.re.tanh(), 1. - d.re.tanh().powi(2));
+        let sigmoid = |d: &Dual<f64>| {
+            let s = 1. / (1. + (-d.re).exp());
+            d.chain(s, s * (1. - s))
+        };
+        let relu = |d: &Dual<f64>| if d.re > 0. { *d } else { Dual::constant(0.) };
+        let hidden = map(w * &x, tanh);
+        let out = map(w * &hidden, sigmoid);
+        let pre = map(w * &x, relu);
+        out.clone().transpose().dot(&out.transpose()) + pre.frobenius_norm()
+    }, &w_value);
+
+    assert_eq!(format!("{:.6}", reverse), format!("{:.6}", forward));
+}
+
+#[test]
+fn activation_derivatives() {
+    assert_eq!(Activation::Relu.derivative(-1.), 0.);
+    assert_eq!(Activation::Relu.derivative(2.), 1.);
+    assert_eq!(Activation::Sigmoid.derivative(0.), 0.25);
+    assert_eq!(Activation::Tanh.derivative(0.), 1.);
+}
+
+#[test]
+#[should_panic(expected = "Backward can only start from a 1x1 value! A=[2,1]")]
+fn backward_non_scalar() {
+    let tape = Tape::new();
+    tape.var(Matrix::from([[1.],[2.]])).backward();
+}
+
+#[test]
+#[should_panic(expected = "Vars from different tapes can't be combined!")]
+fn different_tapes() {
+    let tape_1 = Tape::new();
+    let tape_2 = Tape::new();
+    let _ = tape_1.var(Matrix::from([[1.]])) + tape_2.var(Matrix::from([[1.]]));
+}
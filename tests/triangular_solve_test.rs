@@ -0,0 +1,83 @@
+use mm::{matrix::*, structured::*, triangular_solve::*};
+
+#[test]
+fn lower_triangular() {
+    let l = Matrix::from(
+        [[2.,0.,0.],
+         [1.,1.,0.],
+         [4.,-1.,2.]]);
+    let b = Matrix::from([[2.,3.,6.]]).transpose();
+    let x = solve_lower_triangular(&l, &b, TriangularSolveOptions::default());
+    assert_eq!(format!("{:.2}", x), "1.00\n2.00\n2.00");
+}
+
+#[test]
+fn upper_part_is_ignored() {
+    let l = Matrix::from(
+        [[2.,9.,9.],
+         [1.,1.,9.],
+         [4.,-1.,2.]]);
+    let b = Matrix::from([[2.,3.,6.]]).transpose();
+    let x = solve_lower_triangular(&l, &b, TriangularSolveOptions::default());
+    assert_eq!(format!("{:.2}", x), "1.00\n2.00\n2.00");
+}
+
+#[test]
+fn upper_triangular_multiple_rhs() {
+    let u = Matrix::from(
+        [[2.,1.,4.],
+         [0.,1.,-1.],
+         [0.,0.,2.]]);
+    let b = Matrix::from(
+        [[15.,2.],
+         [1.,0.],
+         [4.,0.]]);
+    let x = solve_upper_triangular(&u, &b, TriangularSolveOptions::default());
+    assert_eq!(format!("{:.2}", x), "2.00,1.00\n3.00,0.00\n2.00,0.00");
+}
+
+#[test]
+fn unit_diagonal() {
+    let l = Matrix::from(
+        [[5.,0.],
+         [3.,7.]]);
+    let b = Matrix::from([[1.,5.]]).transpose();
+    let options = TriangularSolveOptions { unit_diagonal: true, ..Default::default() };
+    let x = solve_lower_triangular(&l, &b, options);
+    assert_eq!(format!("{:.2}", x), "1.00\n2.00");
+}
+
+#[test]
+fn transposed() {
+    let l = Matrix::from(
+        [[2.,0.,0.],
+         [1.,1.,0.],
+         [4.,-1.,2.]]);
+    let b = Matrix::from([[15.,1.,4.]]).transpose();
+    let options = TriangularSolveOptions { transpose: true, ..Default::default() };
+    let x = solve_lower_triangular(&l, &b, options);
+    assert_eq!(format!("{:.2}", x), "2.00\n3.00\n2.00");
+
+    let x = solve_upper_triangular(&l.clone().transpose(), &Matrix::from([[2.,3.,6.]]).transpose(), options);
+    assert_eq!(format!("{:.2}", x), "1.00\n2.00\n2.00");
+}
+
+#[test]
+fn structured_input() {
+    let l = LowerTriangular::from_matrix(&Matrix::from(
+        [[2.,0.],
+         [1.,1.]]));
+    let b = Matrix::from([[2.,3.]]).transpose();
+    let x = solve_lower_triangular(&l, &b, TriangularSolveOptions::default());
+    assert_eq!(format!("{:.2}", x), "1.00\n2.00");
+
+    let x = solve_upper_triangular(&l.transpose(), &b, TriangularSolveOptions { transpose: true, ..Default::default() });
+    assert_eq!(format!("{:.2}", x), "1.00\n2.00");
+}
+
+#[test]
+#[should_panic(expected = "Triangular matrix must be square! A=[2,3]")]
+fn not_square() {
+    let a = Matrix::new_fill(2, 3, 1.);
+    let _ = solve_upper_triangular(&a, &Matrix::new_fill(2, 1, 1.), TriangularSolveOptions::default());
+}
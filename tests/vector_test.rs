@@ -0,0 +1,149 @@
+use mm::{lu_decomposition::*, matrix::*, rational::*, vector::*};
+
+#[test]
+fn construction_and_conversions() {
+    let v = Vector::from([1,2,3]);
+    assert_eq!(v.len(), 3);
+    assert_eq!(format!("{v}"), "1,2,3");
+    assert_eq!(format!("{}", v.to_column()), "1\n2\n3");
+    assert_eq!(format!("{}", v.to_row()), "1,2,3");
+    assert_eq!(format!("{}", Matrix::from(v.clone())), "1\n2\n3");
+
+    // Rows and columns both convert back
+    assert_eq!(Vector::from_matrix(&Matrix::from([[1],[2],[3]])), v);
+    assert_eq!(Vector::from_matrix(&Matrix::from([[1,2,3]])), v);
+
+    let w: Vector<i32> = (1..=3).map(|x| x * x).collect();
+    assert_eq!(format!("{}", w), "1,4,9");
+    assert_eq!(w[2], 9);
+    assert_eq!(format!("{}", Vector::<i32>::basis(3, 1)), "0,1,0");
+    assert_eq!(format!("{:.2}", Vector::from([0.5, 1.])), "0.50,1.00");
+}
+
+#[test]
+#[should_panic(expected = "Matrix is not a vector! A=[2,2]")]
+fn from_matrix_not_a_vector() {
+    Vector::from_matrix(&Matrix::from([[1,2],[3,4]]));
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds! [3] / [3]")]
+fn index_out_of_bounds() {
+    let _ = Vector::from([1,2,3])[3];
+}
+
+#[test]
+fn products() {
+    let u = Vector::from([1,2,3]);
+    let v = Vector::from([4,5,6]);
+    assert_eq!(u.dot(&v), 32);
+    assert_eq!(format!("{}", u.cross(&v)), "-3,6,-3");
+    assert_eq!(u.cross(&v).dot(&u), 0);
+    assert_eq!(format!("{}", u.outer(&Vector::from([1,-1]))), "1,-1\n2,-2\n3,-3");
+    assert_eq!(Vector::<i32>::new().dot(&Vector::new()), 0);
+}
+
+#[test]
+#[should_panic(expected = "Dot operation only allowed on vectors of the same length. Vector A length=3, Vector B length=2")]
+fn dot_different_lengths() {
+    Vector::from([1,2,3]).dot(&Vector::from([1,2]));
+}
+
+#[test]
+#[should_panic(expected = "Cross product is only defined for 3D vectors! Vector A length=2, Vector B length=2")]
+fn cross_not_3d() {
+    Vector::from([1,2]).cross(&Vector::from([3,4]));
+}
+
+#[test]
+fn shape_aware_matrix_products() {
+    let row = Matrix::from([[1,2,3]]);
+    let col = Matrix::from([[4],[5],[6]]);
+    assert_eq!(row.dot(&col), 32);
+    assert_eq!(col.dot(&row), 32);
+    assert_eq!(format!("{}", col.cross(&row)), "3\n-6\n3");
+    assert_eq!(format!("{}", row.cross(&col)), "-3,6,-3");
+    assert_eq!(format!("{}", row.outer(&col)), "4,5,6\n8,10,12\n12,15,18");
+}
+
+#[test]
+fn normalize_project_and_angle() {
+    let v = Vector::from([3.,4.]);
+    assert_eq!(v.norm(), 5.);
+    assert_eq!(format!("{}", v.normalize()), "0.6,0.8");
+
+    let x = Vector::from([1.,0.]);
+    assert_eq!(format!("{}", v.project_onto(&x)), "3,0");
+    assert_eq!(format!("{}", v.reject_from(&x)), "0,4");
+
+    assert!((x.angle_between(&Vector::from([0.,2.])) - core::f64::consts::FRAC_PI_2).abs() < 1e-15);
+    assert!((x.angle_between(&Vector::from([-1.,0.])) - core::f64::consts::PI).abs() < 1e-15);
+    assert_eq!(x.angle_between(&Vector::from([5.,0.])), 0.);
+
+    // acos(cos) would round this to 0
+    let angle = x.angle_between(&Vector::from([1., 1e-10]));
+    assert!((angle - 1e-10).abs() < 1e-20);
+
+    // Exact projection for rationals
+    let r = |n| Rational::from(n);
+    let a = Vector::from([r(1), r(2)]);
+    let b = Vector::from([r(3), r(1)]);
+    assert_eq!(format!("{}", a.project_onto(&b)), "3/2,1/2");
+}
+
+#[test]
+#[should_panic(expected = "Cannot normalize a zero vector")]
+fn normalize_zero() {
+    Vector::from([0.,0.]).normalize();
+}
+
+#[test]
+#[should_panic(expected = "Cannot project onto a zero vector")]
+fn project_onto_zero() {
+    Vector::from([1.,2.]).project_onto(&Vector::from([0.,0.]));
+}
+
+#[test]
+fn operators() {
+    let u = Vector::from([1,2,3]);
+    let v = Vector::from([4,5,6]);
+    assert_eq!(format!("{}", &u + &v), "5,7,9");
+    assert_eq!(format!("{}", v.clone() - u.clone()), "3,3,3");
+    assert_eq!(format!("{}", -&u), "-1,-2,-3");
+    assert_eq!(format!("{}", &u * 2), "2,4,6");
+    assert_eq!(format!("{}", Vector::from([2.,4.]) / 2.), "1,2");
+
+    let mut w = u.clone();
+    w += &v;
+    w -= v;
+    assert_eq!(w, u);
+}
+
+#[test]
+fn matrix_vector_products() {
+    let a = Matrix::from([[1,2,3],[4,5,6]]);
+
+    // A * v reads v as a column, v * A as a row
+    assert_eq!(format!("{}", &a * &Vector::from([1,0,-1])), "-2,-2");
+    assert_eq!(format!("{}", Vector::from([1,1]) * &a), "5,7,9");
+    assert_eq!(format!("{}", a.clone() * Vector::from([1,1,1])), "6,15");
+
+    // Straight into and out of the solvers
+    let m = Matrix::from([[2.,1.],[1.,3.]]);
+    let b = Vector::from([3.,5.]);
+    let x = Vector::from_matrix(&lu_solve(&lu_gauss(&m), &b.clone().into()));
+    assert_eq!(format!("{:.6}", x), "0.800000,1.400000");
+    assert_eq!(format!("{:.6}", &m * &x), "3.000000,5.000000");
+}
+
+#[test]
+#[should_panic(expected = "Can't multiply matrix by vector A*v: A.width (3) != v.len (2)")]
+fn matrix_vector_wrong_size() {
+    let _ = Matrix::from([[1,2,3],[4,5,6]]) * Vector::from([1,2]);
+}
+
+#[test]
+#[should_panic(expected = "Can't multiply vector by matrix v*A: v.len (3) != A.height (2)")]
+fn vector_matrix_wrong_size() {
+    let _ = Vector::from([1,2,3]) * Matrix::from([[1,2,3],[4,5,6]]);
+}